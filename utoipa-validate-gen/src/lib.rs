@@ -1,36 +1,166 @@
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::{quote, quote_spanned};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::cell::RefCell;
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
+use syn::parse::ParseStream;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Field, Fields, GenericParam,
-    Generics, Index, Meta, MetaNameValue, Token, Type,
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Expr, ExprLit, ExprRange, Field,
+    Fields, GenericArgument, GenericParam, Generics, Index, Lit, LitStr, Meta, MetaList,
+    MetaNameValue, PathArguments, RangeLimits, Token, Type, Variant,
 };
 
-#[proc_macro_derive(Validatable)]
+#[proc_macro_derive(Validatable, attributes(validate))]
 pub fn derive_validatable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    if let Data::Union(data) = &input.data {
+        return syn::Error::new_spanned(data.union_token, "Union types are not supported")
+            .to_compile_error()
+            .into();
+    }
+
     let name = input.ident;
     let generics = add_trait_bounds(input.generics);
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let validator_name_str = format!("{}Validator", name.to_string());
+    let validator_name_str = format!("{}Validator", name);
     let validator_name = Ident::new(&validator_name_str, name.span());
-    let checks = create_checks(&name, input.data);
+    let normalizations = create_normalizations(&name, &input.data);
+    let rename_all = parse_rename_all(&input.attrs);
+    let content = parse_adjacent_content(&input.attrs);
+    let constraints = create_constraints(&input.data, rename_all.as_deref(), content.as_deref());
+    let labels = create_labels(&input.data, rename_all.as_deref(), content.as_deref());
+    // Overrides are type-erased via Any which requires 'static field types.
+    let overridable = generics
+        .params
+        .iter()
+        .all(|param| matches!(param, GenericParam::Const(_)));
+    let patterns = PatternFunctions::default();
+    let checks = create_checks(
+        &name,
+        input.data.clone(),
+        overridable,
+        rename_all.clone(),
+        content.as_deref(),
+        CheckMode::All,
+        &patterns,
+    );
+    let first_checks = create_checks(
+        &name,
+        input.data.clone(),
+        overridable,
+        rename_all.clone(),
+        content.as_deref(),
+        CheckMode::FailFast,
+        &patterns,
+    );
+    let with_overrides = if overridable {
+        create_override_setters(&input.data, rename_all.as_deref(), content.as_deref())
+    } else {
+        vec![]
+    };
+
+    let validate_changed = parse_container_flag(&input.attrs, "changed").then(|| {
+        let changed_checks = create_checks(
+            &name,
+            input.data,
+            overridable,
+            rename_all,
+            content.as_deref(),
+            CheckMode::Changed,
+            &patterns,
+        );
+
+        quote! {
+            impl #impl_generics #validator_name #ty_generics #where_clause {
+                /// Validates the fields of the value that differ from the previous value.
+                pub fn validate_changed(&self, path: &utoipa_validate::ValidationPath, value: &#name #ty_generics, previous: &#name #ty_generics, errors: &mut dyn utoipa_validate::ErrorSink) {
+                    #changed_checks
+                }
+            }
+
+            impl #impl_generics utoipa_validate::ValidateChanged for #name #ty_generics #where_clause {
+                fn validate_changed(&self, previous: &Self) -> std::result::Result<(), std::vec::Vec<utoipa_validate::ValidationError>> {
+                    let mut errors = std::vec::Vec::new();
+                    <#validator_name #ty_generics as std::default::Default>::default().validate_changed(&utoipa_validate::ValidationPath::Root, self, previous, &mut errors);
+
+                    if errors.is_empty() {
+                        std::result::Result::Ok(())
+                    } else {
+                        std::result::Result::Err(errors)
+                    }
+                }
+            }
+        }
+    });
+
+    let pattern_functions = patterns.into_definitions();
 
     let output = quote! {
         impl #impl_generics utoipa_validate::Validatable for #name #ty_generics #where_clause {
-            type DefaultValidator = #validator_name;
+            type DefaultValidator = #validator_name #ty_generics;
         }
 
-        #[derive(Default)]
-        pub struct #validator_name {}
+        pub struct #validator_name #impl_generics #where_clause {
+            overrides: std::option::Option<std::boxed::Box<utoipa_validate::FieldOverrides>>,
+            phantom: std::marker::PhantomData<fn(&#name #ty_generics)>,
+        }
+
+        impl #impl_generics std::default::Default for #validator_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    overrides: std::option::Option::None,
+                    phantom: std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #impl_generics #validator_name #ty_generics #where_clause {
+            /// Creates the validator like the Default implementation.
+            pub fn new() -> Self {
+                std::default::Default::default()
+            }
+
+            /// Returns the constraints of the validated fields.
+            pub fn constraints() -> std::vec::Vec<utoipa_validate::FieldConstraint> {
+                std::vec![#(#constraints),*]
+            }
+
+            /// Returns the labels of the fields as pairs of field name and label, see
+            /// `ValidationError::labeled`.
+            pub fn labels() -> &'static [(&'static str, &'static str)] {
+                &[#(#labels),*]
+            }
+
+            #(#with_overrides)*
 
-        impl utoipa_validate::Validator<#name> for #validator_name {
-            fn validate(&self, path: &utoipa_validate::ValidationPath, value: &#name, errors: &mut std::vec::Vec<utoipa_validate::ValidationError>) {
+            #(#pattern_functions)*
+        }
+
+        impl #impl_generics utoipa_validate::Validator<#name #ty_generics> for #validator_name #ty_generics #where_clause {
+            fn validate(&self, path: &utoipa_validate::ValidationPath, value: &#name #ty_generics, errors: &mut dyn utoipa_validate::ErrorSink) {
                 #checks
             }
+
+            fn validate_first(&self, path: &utoipa_validate::ValidationPath, value: &#name #ty_generics) -> std::ops::ControlFlow<utoipa_validate::ValidationError> {
+                #first_checks
+
+                std::ops::ControlFlow::Continue(())
+            }
+        }
+
+        #validate_changed
+
+        impl #impl_generics utoipa_validate::Normalizable for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn normalize(&mut self) {
+                let value = self;
+
+                #normalizations
+            }
         }
     };
 
@@ -49,16 +179,81 @@ fn add_trait_bounds(mut generics: Generics) -> Generics {
     generics
 }
 
-fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
+/// Functions of the generated validator that compile the regexes of the pattern keywords. The
+/// checks of all modes call the same function, so each pattern is compiled at most once.
+#[derive(Default)]
+struct PatternFunctions {
+    /// Pairs of the pattern expression and the definition of its function.
+    functions: RefCell<Vec<(String, TokenStream)>>,
+}
+
+impl PatternFunctions {
+    /// Returns the call of the function that returns the compiled pattern. The function is
+    /// defined on the first call for the pattern.
+    fn call(&self, value: &Expr) -> TokenStream {
+        let key = value.to_token_stream().to_string();
+        let mut functions = self.functions.borrow_mut();
+        let index = match functions.iter().position(|(pattern, _)| *pattern == key) {
+            Some(index) => index,
+            None => {
+                let name = format_ident!("__pattern_{}", functions.len());
+
+                functions.push((
+                    key,
+                    quote! {
+                        utoipa_validate::__require_regex! {
+                            @item
+                            #[doc(hidden)]
+                            fn #name() -> &'static utoipa_validate::regex::Regex {
+                                static PATTERN: std::sync::OnceLock<utoipa_validate::regex::Regex> = std::sync::OnceLock::new();
+
+                                PATTERN.get_or_init(|| utoipa_validate::regex::Regex::new(#value).unwrap())
+                            }
+                        }
+                    },
+                ));
+
+                functions.len() - 1
+            }
+        };
+        let name = format_ident!("__pattern_{}", index);
+
+        quote!(Self::#name())
+    }
+
+    fn into_definitions(self) -> Vec<TokenStream> {
+        self.functions
+            .into_inner()
+            .into_iter()
+            .map(|(_, definition)| definition)
+            .collect()
+    }
+}
+
+/// Creates the checks of all fields. If overridable is set, the validators of the fields can be
+/// replaced via the `with_override` method of the generated validator. The serde rename_all rule
+/// of the container is applied to the field names of structs, the content key of adjacently
+/// tagged enums replaces the variant names.
+fn create_checks(
+    self_type_name: &Ident,
+    data: Data,
+    overridable: bool,
+    rename_all: Option<String>,
+    content: Option<&str>,
+    mode: CheckMode,
+    patterns: &PatternFunctions,
+) -> TokenStream {
     match data {
         Data::Struct(data) => match data.fields {
             Fields::Named(fields) => {
                 let recurse = fields.named.into_iter().map(|field| {
                     let span = field.span();
                     let field_name = field.ident.clone().unwrap();
-                    let field_name_str = field_name.to_string();
+                    let field_name_str =
+                        field_path_name(&field, field_name.to_string(), rename_all.as_deref());
                     let checks = create_checks_for_field(
                         field,
+                        overridable.then(|| field_name_str.clone()),
                         quote! {
                             value.#field_name
                         },
@@ -68,6 +263,13 @@ fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
                                 name: #field_name_str,
                             }
                         },
+                        mode,
+                        patterns,
+                    );
+                    let checks = mode.only_changed(
+                        checks,
+                        quote!(value.#field_name),
+                        quote!(previous.#field_name),
                     );
 
                     quote_spanned! {span=>
@@ -90,6 +292,7 @@ fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
                         let field_index_str = index.to_string();
                         let checks = create_checks_for_field(
                             field,
+                            overridable.then(|| field_index_str.clone()),
                             quote! {
                                 value.#field_index
                             },
@@ -99,6 +302,13 @@ fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
                                     name: #field_index_str,
                                 }
                             },
+                            mode,
+                            patterns,
+                        );
+                        let checks = mode.only_changed(
+                            checks,
+                            quote!(value.#field_index),
+                            quote!(previous.#field_index),
                         );
 
                         quote_spanned! {span=>
@@ -116,7 +326,9 @@ fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
         },
         Data::Enum(data) => {
             let recurse = data.variants.into_iter().map(|variant| {
+                let variant_path = variant_path(&variant, rename_all.as_deref(), content);
                 let variant_name = variant.ident;
+                let rename_all = parse_rename_all(&variant.attrs);
 
                 let fields =
                     match variant.fields.iter().next() {
@@ -155,178 +367,1943 @@ fn create_checks(self_type_name: &Ident, data: Data) -> TokenStream {
                             .clone()
                             .ident
                             .unwrap_or_else(|| generate_field_name(index));
-                        let field_name_str = format!("{}.{}", variant_name, field_name);
-
-                        create_checks_for_field(
-                            field,
-                            quote! {
-                                #field_name
+                        let field_name_str =
+                            field_path_name(&field, field_name.to_string(), rename_all.as_deref());
+                        let field_path = match &variant_path {
+                            VariantPath::Parent(parent) => quote! {
+                                utoipa_validate::ValidationPath::Field {
+                                    parent: &utoipa_validate::ValidationPath::Field {
+                                        parent: path,
+                                        name: #parent,
+                                    },
+                                    name: #field_name_str,
+                                }
                             },
-                            quote! {
+                            VariantPath::Newtype(content) => quote! {
                                 utoipa_validate::ValidationPath::Field {
                                     parent: path,
-                                    name: #field_name_str,
+                                    name: #content,
                                 }
                             },
+                        };
+
+                        create_checks_for_field(
+                            field,
+                            overridable.then(|| variant_field_path(&variant_path, field_name_str)),
+                            quote! {
+                                (*#field_name)
+                            },
+                            field_path,
+                            mode,
+                            patterns,
                         )
                     });
 
                 quote! {
+                    #[allow(unused_variables)]
                     #self_type_name::#variant_name #fields => {
                         #(#checks)*
                     }
                 }
             });
 
+            // Fields of different variants can not be compared, so enums are validated as a whole
+            // if they changed.
+            mode.only_changed(
+                quote! {
+                    match value {
+                        #(#recurse)*
+                    }
+                },
+                quote!(*value),
+                quote!(*previous),
+            )
+        }
+        Data::Union(_) => unreachable!("Unions are rejected by derive_validatable"),
+    }
+}
+
+/// Creates the normalizations of the fields that are annotated with normalizers like
+/// `#[validate(trim)]`.
+fn create_normalizations(self_type_name: &Ident, data: &Data) -> TokenStream {
+    match data {
+        Data::Struct(data) => {
+            let normalizations = data.fields.iter().enumerate().map(|(index, field)| {
+                let field_expr = match &field.ident {
+                    Some(field_name) => quote!(value.#field_name),
+                    None => {
+                        let field_index = Index::from(index);
+
+                        quote!(value.#field_index)
+                    }
+                };
+
+                create_normalizations_for_field(field, field_expr)
+            });
+
+            quote! {
+                #(#normalizations)*
+            }
+        }
+        Data::Enum(data) => {
+            let recurse = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let field_names = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| {
+                        field
+                            .ident
+                            .clone()
+                            .unwrap_or_else(|| generate_field_name(index))
+                    })
+                    .collect::<Vec<_>>();
+                let fields = match &variant.fields {
+                    Fields::Named(_) => quote! {
+                        {
+                            #(#field_names, )*
+                        }
+                    },
+                    Fields::Unnamed(_) => quote! {
+                        (
+                            #(#field_names, )*
+                        )
+                    },
+                    Fields::Unit => quote!(),
+                };
+                let normalizations =
+                    variant
+                        .fields
+                        .iter()
+                        .zip(&field_names)
+                        .map(|(field, field_name)| {
+                            create_normalizations_for_field(field, quote!(*#field_name))
+                        });
+
+                quote! {
+                    #[allow(unused_variables)]
+                    #self_type_name::#variant_name #fields => {
+                        #(#normalizations)*
+                    }
+                }
+            });
+
             quote! {
                 match value {
                     #(#recurse)*
                 }
             }
         }
-        Data::Union(_) => {
-            unimplemented!("Union types are not supported")
-        }
+        Data::Union(_) => unreachable!("Unions are rejected by derive_validatable"),
     }
 }
 
-fn create_checks_for_field(
-    field: Field,
-    field_expr: TokenStream,
-    field_path: TokenStream,
-) -> TokenStream {
-    let field_type = field.ty;
-    let is_option = is_option(&field_type);
-
-    let checks = field.attrs.into_iter().map(|attribute| {
-        if attribute.path().is_ident("schema") || attribute.path().is_ident("param") {
-            create_checks_for_schema_attribute(&field_expr, is_option, attribute)
-        } else {
-            quote!()
-        }
-    });
+fn create_normalizations_for_field(field: &Field, field_expr: TokenStream) -> TokenStream {
+    let normalizers = FieldOptions::parse(&field.attrs).normalizers;
 
     quote! {
-        {
-            let child_path = #field_path;
-
-            <#field_type as utoipa_validate::Validatable>::validate_ex(&#field_expr, &child_path, errors);
-            #(#checks)*
-        }
+        #(utoipa_validate::Normalizer::normalize(&#normalizers, &mut #field_expr);)*
     }
 }
 
-fn create_checks_for_schema_attribute(
-    field_expr: &TokenStream,
-    is_option: bool,
-    attribute: Attribute,
-) -> TokenStream {
-    let checks = attribute.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated).unwrap().into_iter().map(|meta| {
-        match match meta {
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("exclusive_maximum") => {
-                let _ = eq_token;
+/// Creates the descriptions of the constraints of all validated fields as returned by the
+/// generated `constraints()` method. Fields without constraints are omitted.
+fn create_constraints(
+    data: &Data,
+    rename_all: Option<&str>,
+    content: Option<&str>,
+) -> Vec<TokenStream> {
+    let fields = field_path_names(data, rename_all, content);
 
-                Some(quote! {
-                    utoipa_validate::ExclusiveMaximumValidator::new(#value)
+    fields
+        .into_iter()
+        .filter(|(_, field)| !FieldOptions::parse(&field.attrs).is_skipped())
+        .filter_map(|(field_name, field)| {
+            let constraints = field
+                .attrs
+                .iter()
+                .filter(|attribute| {
+                    attribute.path().is_ident("schema")
+                        || attribute.path().is_ident("param")
+                        || attribute.path().is_ident("validate")
                 })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("exclusive_minimum") => {
-                let _ = eq_token;
-
-                Some(quote! {
-                    utoipa_validate::ExclusiveMinimumValidator::new(#value)
+                // Invalid attributes are reported by the checks.
+                .flat_map(|attribute| parse_keywords(attribute).unwrap_or_default())
+                .filter(|meta| {
+                    !create_validators(meta.clone(), &PatternFunctions::default()).is_empty()
                 })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("maximum") => {
-                let _ = eq_token;
+                .map(|meta| {
+                    let keyword = meta.path().to_token_stream().to_string();
+                    let value = match meta {
+                        Meta::Path(_) => String::new(),
+                        Meta::List(list) => list.tokens.to_string(),
+                        Meta::NameValue(MetaNameValue {
+                            value:
+                                Expr::Lit(ExprLit {
+                                    lit: Lit::Str(value),
+                                    ..
+                                }),
+                            ..
+                        }) => value.value(),
+                        Meta::NameValue(MetaNameValue { value, .. }) => {
+                            value.to_token_stream().to_string()
+                        }
+                    };
 
-                Some(quote! {
-                    utoipa_validate::MaximumValidator::new(#value)
+                    quote!((#keyword, #value))
                 })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("minimum") => {
-                let _ = eq_token;
+                .collect::<Vec<_>>();
 
+            if constraints.is_empty() {
+                None
+            } else {
                 Some(quote! {
-                    utoipa_validate::MinimumValidator::new(#value)
+                    utoipa_validate::FieldConstraint {
+                        field: #field_name,
+                        constraints: std::vec![#(#constraints),*],
+                    }
                 })
             }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("max_items") => {
-                let _ = eq_token;
+        })
+        .collect()
+}
 
-                Some(quote! {
-                    utoipa_validate::MaxItemsValidator::new(#value)
-                })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("min_items") => {
-                let _ = eq_token;
+/// Creates the labels given via `#[validate(label = "...")]` as returned by the generated
+/// `labels()` method.
+fn create_labels(data: &Data, rename_all: Option<&str>, content: Option<&str>) -> Vec<TokenStream> {
+    field_path_names(data, rename_all, content)
+        .into_iter()
+        .filter_map(|(field_name, field)| {
+            let label = FieldOptions::parse(&field.attrs).label?;
 
-                Some(quote! {
-                    utoipa_validate::MinItemsValidator::new(#value)
-                })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("max_length") => {
-                let _ = eq_token;
+            Some(quote!((#field_name, #label)))
+        })
+        .collect()
+}
 
-                Some(quote! {
-                    utoipa_validate::MaxLengthValidator::new(#value)
-                })
-            }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("min_length") => {
-                let _ = eq_token;
+/// Creates a `with_<field>_override` method per validated field. Each method only accepts
+/// validators of the field type, the fields of enums are prefixed with the snake case variant
+/// name, e.g. `with_variant_field_override`.
+fn create_override_setters(
+    data: &Data,
+    rename_all: Option<&str>,
+    content: Option<&str>,
+) -> Vec<TokenStream> {
+    let setter_names: Vec<String> = match data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| field_setter_name(field, index))
+            .collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                let variant_name = convert_variant_case(&variant.ident.to_string(), "snake_case");
 
-                Some(quote! {
-                    utoipa_validate::MinLengthValidator::new(#value)
-                })
+                variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, field)| {
+                        format!("{}_{}", variant_name, field_setter_name(field, index))
+                    })
+            })
+            .collect(),
+        Data::Union(_) => vec![],
+    };
+
+    field_path_names(data, rename_all, content)
+        .into_iter()
+        .zip(setter_names)
+        .filter(|((_, field), _)| !FieldOptions::parse(&field.attrs).is_skipped())
+        .map(|((field_name, field), setter_name)| {
+            let field_type = &field.ty;
+            let setter = format_ident!("with_{}_override", setter_name, span = field.span());
+            let doc = format!(
+                " Replaces the validation of the field `{}` with the passed validator.",
+                field_name
+            );
+
+            // The generic is named to not collide with the generics of the type.
+            quote! {
+                #[doc = #doc]
+                pub fn #setter<__Validator>(mut self, validator: __Validator) -> Self
+                where
+                    __Validator: utoipa_validate::Validator<#field_type> + Send + Sync + 'static,
+                {
+                    self.overrides
+                        .get_or_insert_with(std::default::Default::default)
+                        .insert::<#field_type, __Validator>(#field_name, validator);
+                    self
+                }
             }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("multiple_of") => {
-                let _ = eq_token;
+        })
+        .collect()
+}
 
-                Some(quote! {
-                    utoipa_validate::MultipleOfValidator::new(#value)
-                })
+/// Returns the name of the field in the override setters, the index for tuple fields.
+fn field_setter_name(field: &Field, index: usize) -> String {
+    match &field.ident {
+        Some(ident) => ident.unraw().to_string(),
+        None => index.to_string(),
+    }
+}
+
+/// Returns the fields together with their names in error paths, e.g. "Variant.field" for enums.
+fn field_path_names<'a>(
+    data: &'a Data,
+    rename_all: Option<&str>,
+    content: Option<&str>,
+) -> Vec<(String, &'a Field)> {
+    match data {
+        Data::Struct(data) => data
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let field_name = match &field.ident {
+                    Some(ident) => field_path_name(field, ident.to_string(), rename_all),
+                    None => index.to_string(),
+                };
+
+                (field_name, field)
+            })
+            .collect::<Vec<_>>(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| {
+                let variant_path = variant_path(variant, rename_all, content);
+                let rename_all = parse_rename_all(&variant.attrs);
+
+                variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, field)| {
+                        let field_name = field_path_name(
+                            field,
+                            field
+                                .ident
+                                .clone()
+                                .unwrap_or_else(|| generate_field_name(index))
+                                .to_string(),
+                            rename_all.as_deref(),
+                        );
+
+                        (variant_field_path(&variant_path, field_name), field)
+                    })
+            })
+            .collect(),
+        Data::Union(_) => vec![],
+    }
+}
+
+/// Path of the fields of a variant in the serialized form. For adjacently tagged enums the fields
+/// are placed below the content key instead of the variant name, the single field of newtype
+/// variants is the content itself.
+enum VariantPath {
+    Parent(String),
+    Newtype(String),
+}
+
+fn variant_path(variant: &Variant, rename_all: Option<&str>, content: Option<&str>) -> VariantPath {
+    let is_newtype =
+        variant.fields.len() == 1 && variant.fields.iter().all(|field| field.ident.is_none());
+
+    match content {
+        Some(content) if is_newtype => VariantPath::Newtype(content.to_owned()),
+        Some(content) => VariantPath::Parent(content.to_owned()),
+        None => VariantPath::Parent(variant_path_name(variant, rename_all)),
+    }
+}
+
+/// Returns the name of the variant in error paths. Like serde, an explicit rename wins over the
+/// rename_all rule of the enum.
+fn variant_path_name(variant: &Variant, rename_all: Option<&str>) -> String {
+    let name = variant.ident.to_string();
+
+    match (FieldOptions::parse(&variant.attrs).rename, rename_all) {
+        (Some(rename), _) => rename,
+        (None, Some(rule)) => convert_variant_case(&name, rule),
+        (None, None) => name,
+    }
+}
+
+fn variant_field_path(variant_path: &VariantPath, field_name: String) -> String {
+    match variant_path {
+        VariantPath::Parent(parent) => format!("{}.{}", parent, field_name),
+        VariantPath::Newtype(content) => content.clone(),
+    }
+}
+
+fn create_checks_for_field(
+    field: Field,
+    override_name: Option<String>,
+    field_expr: TokenStream,
+    field_path: TokenStream,
+    mode: CheckMode,
+    patterns: &PatternFunctions,
+) -> TokenStream {
+    let fail_fast = mode == CheckMode::FailFast;
+    let options = FieldOptions::parse(&field.attrs);
+
+    if !options.errors.is_empty() {
+        let errors = &options.errors;
+
+        return quote!(#(#errors)*);
+    }
+
+    if options.is_skipped() {
+        return quote!();
+    }
+
+    let field_type = field.ty;
+    let wrappers = find_wrappers(&field_type);
+    let recursion = if options.opaque_bytes || is_primitive(&field_type) {
+        quote!()
+    } else if options.sorted {
+        let validator = quote! {
+            <<#field_type as utoipa_validate::Validatable>::DefaultValidator as std::default::Default>::default().sorted()
+        };
+
+        if fail_fast {
+            quote! {
+                utoipa_validate::Validator::validate_first(&#validator, &child_path, &#field_expr)?;
             }
-            Meta::NameValue(MetaNameValue { path, eq_token, value }) if path.is_ident("pattern") => {
-                let _ = eq_token;
+        } else {
+            quote! {
+                utoipa_validate::Validator::validate(&#validator, &child_path, &#field_expr, errors);
+            }
+        }
+    } else if fail_fast {
+        quote! {
+            utoipa_validate::Validator::validate_first(
+                &<<#field_type as utoipa_validate::Validatable>::DefaultValidator as std::default::Default>::default(),
+                &child_path,
+                &#field_expr,
+            )?;
+        }
+    } else {
+        quote! {
+            <#field_type as utoipa_validate::Validatable>::validate_ex(&#field_expr, &child_path, errors);
+        }
+    };
 
-                Some(quote! {
-                    utoipa_validate::PatternValidator::new(regex::Regex::new(#value).unwrap())
-                })
+    let checks = field
+        .attrs
+        .into_iter()
+        .filter(|attribute| {
+            attribute.path().is_ident("schema")
+                || attribute.path().is_ident("param")
+                || attribute.path().is_ident("validate")
+        })
+        .flat_map(|attribute| {
+            create_checks_for_schema_attribute(
+                &wrappers,
+                attribute,
+                options.length_in_bytes,
+                patterns,
+            )
+        })
+        .map(|validator_expr| match &options.message {
+            Some(message) => quote! {
+                utoipa_validate::MessageValidator::new(#validator_expr, #message)
+            },
+            None => validator_expr,
+        })
+        .map(|validator_expr| {
+            if options.warning {
+                quote! {
+                    utoipa_validate::WarningValidator::new(#validator_expr)
+                }
+            } else {
+                validator_expr
             }
-            _ => None,
-        } {
-            None => quote!(),
-            Some(validator_expr) => if is_option {
+        })
+        .map(|validator_expr| {
+            if fail_fast {
                 quote! {
-                    utoipa_validate::OptionValidator::new(#validator_expr).validate(&child_path, &#field_expr, errors);
+                    utoipa_validate::Validator::validate_first(&#validator_expr, &child_path, &#field_expr)?;
                 }
             } else {
                 quote! {
                     #validator_expr.validate(&child_path, &#field_expr, errors);
-               }
-            },
-        }
-    });
+                }
+            }
+        })
+        .collect::<Vec<_>>();
 
-    quote! {
+    let mut body = quote! {
+        #recursion
         #(#checks)*
+    };
+
+    if let (Some(override_name), true) = (&override_name, fail_fast) {
+        body = quote! {
+            let overridden = match &self.overrides {
+                std::option::Option::Some(overrides) => {
+                    overrides.validate_first(#override_name, &child_path, &#field_expr)?
+                }
+                std::option::Option::None => false,
+            };
+
+            if !overridden {
+                #body
+            }
+        };
+    } else if let Some(override_name) = override_name {
+        let validate_override = quote! {
+            self.overrides.as_ref().is_some_and(|overrides| {
+                overrides.validate(#override_name, &child_path, &#field_expr, errors)
+            })
+        };
+
+        body = if body.is_empty() {
+            quote! {
+                if let std::option::Option::Some(overrides) = &self.overrides {
+                    overrides.validate(#override_name, &child_path, &#field_expr, errors);
+                }
+            }
+        } else {
+            quote! {
+                if !#validate_override {
+                    #body
+                }
+            }
+        };
     }
-}
 
-fn is_option(t: &Type) -> bool {
-    if let Type::Path(path) = t {
-        path.path
-            .segments
-            .last()
-            .expect("Expected at least one segment")
-            .ident
-            .to_string()
-            == "Option"
-    } else {
-        false
+    if body.is_empty() {
+        return quote!();
+    }
+
+    match options.max_errors {
+        // At most the first error is reported, so only a limit of zero changes the result.
+        Some(max_errors) if fail_fast => quote! {
+            if (#max_errors) > 0usize {
+                let child_path = #field_path;
+
+                #body
+            }
+        },
+        None => quote! {
+            {
+                let child_path = #field_path;
+
+                #body
+            }
+        },
+        Some(max_errors) => quote! {
+            {
+                let child_path = #field_path;
+                let errors = &mut utoipa_validate::MaxErrorsSink::new(errors, #max_errors);
+
+                #body
+            }
+        },
     }
 }
 
+/// Creates the validator expressions for the keywords of the passed attribute.
+fn create_checks_for_schema_attribute(
+    wrappers: &[Wrapper],
+    attribute: Attribute,
+    length_in_bytes: bool,
+    patterns: &PatternFunctions,
+) -> Vec<TokenStream> {
+    let is_validate = attribute.path().is_ident("validate");
+    let metas = match parse_keywords(&attribute) {
+        Ok(metas) => metas,
+        Err(error) => return vec![error.to_compile_error()],
+    };
+
+    metas
+        .into_iter()
+        .flat_map(|meta| {
+            if is_validate && !is_validate_keyword(&meta) {
+                let message = format!(
+                    "Unknown validation keyword `{}`",
+                    meta.path().to_token_stream()
+                );
+
+                return vec![syn::Error::new_spanned(meta.path(), message).to_compile_error()];
+            }
+
+            // Item keywords apply to the innermost values, required to the optional value itself
+            // and all other keywords to the value behind the leading options.
+            let wrappers = if meta.path().is_ident("required") {
+                &[]
+            } else if is_item_keyword(&meta) {
+                wrappers
+            } else {
+                let options = wrappers
+                    .iter()
+                    .take_while(|wrapper| matches!(wrapper, Wrapper::Option | Wrapper::Patch))
+                    .count();
+
+                &wrappers[..options]
+            };
+
+            let validators = match meta {
+                Meta::NameValue(MetaNameValue { path, value, .. })
+                    if length_in_bytes && path.is_ident("max_length") =>
+                {
+                    vec![quote! {
+                        utoipa_validate::MaxByteLengthValidator::new(#value)
+                    }]
+                }
+                Meta::NameValue(MetaNameValue { path, value, .. })
+                    if length_in_bytes && path.is_ident("min_length") =>
+                {
+                    vec![quote! {
+                        utoipa_validate::MinByteLengthValidator::new(#value)
+                    }]
+                }
+                meta => create_validators(meta, patterns),
+            };
+
+            validators
+                .into_iter()
+                .map(move |validator_expr| {
+                    wrappers
+                        .iter()
+                        .rev()
+                        .fold(validator_expr, |validator_expr, wrapper| match wrapper {
+                            Wrapper::Option => quote! {
+                                utoipa_validate::OptionValidator::new(#validator_expr)
+                            },
+                            Wrapper::Patch => quote! {
+                                utoipa_validate::PatchValidator::new(#validator_expr)
+                            },
+                            Wrapper::Vec => quote! {
+                                utoipa_validate::VecValidator::new(#validator_expr)
+                            },
+                            Wrapper::Array => quote! {
+                                utoipa_validate::ArrayValidator::new(#validator_expr)
+                            },
+                            Wrapper::Number => quote! {
+                                utoipa_validate::TransparentValidator::new(#validator_expr)
+                            },
+                        })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses the keywords of a schema attribute. In contrast to `Meta::parse`, the `const` keyword is
+/// accepted as name. The keywords of attributes owned by other crates like `#[schema(...)]` are
+/// parsed one at a time and keywords whose values are no expressions, e.g.
+/// `value_type = Option<String>`, are skipped. Invalid keywords of `#[validate(...)]` are errors.
+fn parse_keywords(attribute: &Attribute) -> syn::Result<Vec<Meta>> {
+    let is_validate = attribute.path().is_ident("validate");
+
+    attribute.parse_args_with(|input: ParseStream| {
+        let mut metas = Vec::new();
+
+        while !input.is_empty() {
+            let fork = input.fork();
+
+            match parse_keyword(&fork) {
+                Ok(meta) if fork.is_empty() || fork.peek(Token![,]) => {
+                    input.advance_to(&fork);
+                    metas.push(meta);
+                }
+                Ok(_) if is_validate => return Err(fork.error("expected `,`")),
+                Err(error) if is_validate => return Err(error),
+                _ => skip_keyword(input)?,
+            }
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(metas)
+    })
+}
+
+fn parse_keyword(input: ParseStream) -> syn::Result<Meta> {
+    if input.peek(Token![const]) {
+        let keyword = input.parse::<Token![const]>()?;
+
+        Ok(Meta::NameValue(MetaNameValue {
+            path: Ident::new("const", keyword.span).into(),
+            eq_token: input.parse()?,
+            value: input.parse()?,
+        }))
+    } else {
+        input.parse()
+    }
+}
+
+/// Skips the tokens up to the next comma that is not nested in angle brackets of a type like
+/// `HashMap<String, i32>`.
+fn skip_keyword(input: ParseStream) -> syn::Result<()> {
+    let mut depth = 0usize;
+
+    while !(input.is_empty() || depth == 0 && input.peek(Token![,])) {
+        input.step(|cursor| {
+            let (token, next) = cursor
+                .token_tree()
+                .ok_or_else(|| cursor.error("unexpected end of input"))?;
+
+            if let TokenTree::Punct(punct) = &token {
+                match punct.as_char() {
+                    '<' => depth += 1,
+                    '>' => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+            }
+
+            Ok(((), next))
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Keywords that create validators, see [create_validators].
+const VALIDATION_KEYWORDS: &[&str] = &[
+    "exclusive_maximum",
+    "exclusive_minimum",
+    "maximum",
+    "minimum",
+    "range",
+    "max_items",
+    "min_items",
+    "unique_items",
+    "max_properties",
+    "min_properties",
+    "max_length",
+    "min_length",
+    "multiple_of",
+    "const",
+    "const_value",
+    "enumeration",
+    "finite",
+    "ascii",
+    "pattern",
+    "pattern_fallible",
+    "format",
+    "url",
+    "required",
+    "with",
+    "allowed_keys",
+    "char_length",
+    "byte_length",
+    "bytes",
+    "key",
+    "value",
+];
+
+/// Keywords of `#[validate(...)]` that configure the validation of a field, see [FieldOptions].
+const OPTION_KEYWORDS: &[&str] = &[
+    "skip",
+    "max_errors",
+    "label",
+    "message",
+    "warning",
+    "length",
+    "sorted",
+    "trim",
+    "lowercase",
+];
+
+/// Keywords that only document or shape the schema but do not constrain values. They are accepted
+/// in `#[validate(...)]` as well such that schema keywords can be moved there unchanged.
+const DOCUMENTATION_KEYWORDS: &[&str] = &[
+    "inline",
+    "title",
+    "description",
+    "example",
+    "default",
+    "deprecated",
+    "nullable",
+    "read_only",
+    "write_only",
+    "value_type",
+    "xml",
+    "rename",
+    "schema_with",
+];
+
+/// Returns whether the keyword is allowed in `#[validate(...)]`.
+fn is_validate_keyword(meta: &Meta) -> bool {
+    VALIDATION_KEYWORDS
+        .iter()
+        .chain(OPTION_KEYWORDS)
+        .chain(DOCUMENTATION_KEYWORDS)
+        .any(|keyword| meta.path().is_ident(keyword))
+}
+
+/// Returns whether the schema keyword constrains single values like numbers and strings in
+/// contrast to containers. Such keywords are applied to the items of vectors.
+fn is_item_keyword(meta: &Meta) -> bool {
+    const ITEM_KEYWORDS: &[&str] = &[
+        "exclusive_maximum",
+        "exclusive_minimum",
+        "maximum",
+        "minimum",
+        "range",
+        "max_length",
+        "min_length",
+        "multiple_of",
+        "finite",
+        "ascii",
+        "pattern",
+        "pattern_fallible",
+        "format",
+        "url",
+        "char_length",
+    ];
+
+    ITEM_KEYWORDS
+        .iter()
+        .any(|keyword| meta.path().is_ident(keyword))
+}
+
+/// Creates the validator expressions for a schema keyword.
+fn create_validators(meta: Meta, patterns: &PatternFunctions) -> Vec<TokenStream> {
+    match meta {
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("exclusive_maximum") => {
+            let _ = eq_token;
+
+            let value = create_bound(value);
+
+            vec![quote! {
+                utoipa_validate::ExclusiveMaximumValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("exclusive_minimum") => {
+            let _ = eq_token;
+
+            let value = create_bound(value);
+
+            vec![quote! {
+                utoipa_validate::ExclusiveMinimumValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("range") => {
+            let _ = eq_token;
+
+            create_range_validators(value)
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("maximum") => {
+            let _ = eq_token;
+
+            let value = create_bound(value);
+
+            vec![quote! {
+                utoipa_validate::MaximumValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("minimum") => {
+            let _ = eq_token;
+
+            let value = create_bound(value);
+
+            vec![quote! {
+                utoipa_validate::MinimumValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("max_items") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MaxItemsValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("min_items") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MinItemsValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("max_properties") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MaxPropertiesValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("min_properties") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MinPropertiesValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("max_length") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MaxLengthValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("min_length") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MinLengthValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("multiple_of") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::MultipleOfValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("const") || path.is_ident("const_value") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::ConstValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("enumeration") => {
+            let _ = eq_token;
+
+            vec![quote! {
+                utoipa_validate::EnumerationValidator::new(#value)
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("pattern") => {
+            let _ = eq_token;
+
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(pattern),
+                ..
+            }) = &value
+            {
+                if let Some(construct) = find_unsupported_regex_construct(&pattern.value()) {
+                    let message = format!(
+                            "The pattern uses {} which is not supported by the regex crate. Lookaround and backreferences cannot be used in patterns.",
+                            construct
+                        );
+
+                    return vec![quote_spanned! {pattern.span()=>
+                        compile_error!(#message)
+                    }];
+                }
+
+                if let Err(error) = regex::Regex::new(&pattern.value()) {
+                    let message =
+                        format!("The pattern is not a valid regular expression: {}", error);
+
+                    return vec![quote_spanned! {pattern.span()=>
+                        compile_error!(#message)
+                    }];
+                }
+            }
+
+            let pattern = patterns.call(&value);
+
+            vec![quote_spanned! {path.span()=>
+                utoipa_validate::__require_regex!(utoipa_validate::PatternValidator::new(#pattern))
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            eq_token,
+            value,
+        }) if path.is_ident("with") => {
+            let _ = eq_token;
+
+            let function = match value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(function),
+                    ..
+                }) => match function.parse::<syn::Path>() {
+                    Ok(function) => function.to_token_stream(),
+                    Err(error) => {
+                        let message = format!("Expected the path of a function: {}", error);
+
+                        return vec![syn::Error::new_spanned(function, message).to_compile_error()];
+                    }
+                },
+                value => value.to_token_stream(),
+            };
+
+            vec![quote! {
+                utoipa_validate::FnValidator::new(#function)
+            }]
+        }
+        Meta::NameValue(MetaNameValue { path, value, .. }) if path.is_ident("pattern_fallible") => {
+            vec![quote_spanned! {path.span()=>
+                utoipa_validate::__require_regex!(utoipa_validate::FalliblePatternValidator::new(&#value))
+            }]
+        }
+        Meta::NameValue(MetaNameValue { path, value, .. }) if path.is_ident("format") => {
+            create_format_validators(value)
+        }
+        Meta::Path(path) if path.is_ident("required") => {
+            vec![quote! {
+                utoipa_validate::RequiredValidator::new()
+            }]
+        }
+        Meta::Path(path) if path.is_ident("unique_items") => {
+            vec![quote! {
+                utoipa_validate::UniqueItemsValidator::new()
+            }]
+        }
+        Meta::NameValue(MetaNameValue {
+            path,
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Bool(unique),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("unique_items") => {
+            if unique.value {
+                vec![quote! {
+                    utoipa_validate::UniqueItemsValidator::new()
+                }]
+            } else {
+                vec![]
+            }
+        }
+        Meta::Path(path) if path.is_ident("finite") => {
+            vec![quote! {
+                utoipa_validate::FiniteValidator::new()
+            }]
+        }
+        Meta::Path(path) if path.is_ident("ascii") => {
+            vec![quote! {
+                utoipa_validate::AsciiValidator::new()
+            }]
+        }
+        Meta::Path(path) if path.is_ident("url") => {
+            vec![quote! {
+                utoipa_validate::UrlValidator::new(&[])
+            }]
+        }
+        Meta::List(list) if list.path.is_ident("url") => {
+            let parse_schemes = |list: MetaList| {
+                if list.path.is_ident("schemes") {
+                    list.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                } else {
+                    Err(syn::Error::new_spanned(list.path, "Expected schemes"))
+                }
+            };
+            let schemes = list
+                .parse_args_with(Punctuated::<MetaList, Token![,]>::parse_terminated)
+                .and_then(|lists| {
+                    lists
+                        .into_iter()
+                        .map(parse_schemes)
+                        .collect::<syn::Result<Vec<_>>>()
+                });
+
+            match schemes {
+                Ok(schemes) => {
+                    let schemes = schemes.into_iter().flatten();
+
+                    vec![quote! {
+                        utoipa_validate::UrlValidator::new(&[#(#schemes),*])
+                    }]
+                }
+                Err(error) => vec![error.to_compile_error()],
+            }
+        }
+        Meta::List(list) if list.path.is_ident("allowed_keys") => {
+            match list.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated) {
+                Ok(keys) => {
+                    let keys = keys.into_iter();
+
+                    vec![quote! {
+                        utoipa_validate::AllowedKeysValidator::new(&[#(#keys),*])
+                    }]
+                }
+                Err(error) => vec![error.to_compile_error()],
+            }
+        }
+        Meta::List(list) if list.path.is_ident("char_length") => create_length_validators(
+            list,
+            ("max", quote!(MaxLengthValidator)),
+            ("min", quote!(MinLengthValidator)),
+        ),
+        Meta::List(list) if list.path.is_ident("byte_length") => create_length_validators(
+            list,
+            ("max", quote!(MaxByteLengthValidator)),
+            ("min", quote!(MinByteLengthValidator)),
+        ),
+        Meta::List(list) if list.path.is_ident("bytes") => create_length_validators(
+            list,
+            ("max_len", quote!(MaxByteLengthValidator)),
+            ("min_len", quote!(MinByteLengthValidator)),
+        ),
+        Meta::List(list) if list.path.is_ident("key") => create_nested_validators(list, patterns)
+            .into_iter()
+            .map(|validator_expr| {
+                quote! {
+                    utoipa_validate::MapKeyValidator::new(#validator_expr)
+                }
+            })
+            .collect(),
+        Meta::List(list) if list.path.is_ident("value") => create_nested_validators(list, patterns)
+            .into_iter()
+            .map(|validator_expr| {
+                quote! {
+                    utoipa_validate::MapValidator::new(#validator_expr)
+                }
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Creates the validator expressions for the schema keywords nested in a list like
+/// `key(max_length = 10)`.
+fn create_nested_validators(list: MetaList, patterns: &PatternFunctions) -> Vec<TokenStream> {
+    let metas = match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(metas) => metas,
+        Err(error) => return vec![error.to_compile_error()],
+    };
+
+    metas
+        .into_iter()
+        .flat_map(|meta| {
+            if VALIDATION_KEYWORDS
+                .iter()
+                .any(|keyword| meta.path().is_ident(keyword))
+            {
+                create_validators(meta, patterns)
+            } else {
+                let message = format!(
+                    "Unknown validation keyword `{}`",
+                    meta.path().to_token_stream()
+                );
+
+                vec![syn::Error::new_spanned(meta.path(), message).to_compile_error()]
+            }
+        })
+        .collect()
+}
+
+/// Creates the validators for a format like `format = Email` or `format = "email"`. The name is
+/// compared case-insensitively. Unknown formats are not validated.
+fn create_format_validators(value: Expr) -> Vec<TokenStream> {
+    let format = match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(format),
+            ..
+        }) => format.value(),
+        Expr::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    };
+
+    match format.to_lowercase().as_str() {
+        "email" => vec![quote! {
+            utoipa_validate::EmailValidator::new()
+        }],
+        "uuid" => vec![quote! {
+            utoipa_validate::UuidValidator::new()
+        }],
+        "date" => vec![quote! {
+            utoipa_validate::DateValidator::new()
+        }],
+        "datetime" | "date-time" => vec![quote! {
+            utoipa_validate::DateTimeValidator::new()
+        }],
+        "ipv4" => vec![quote! {
+            utoipa_validate::Ipv4Validator::new()
+        }],
+        "ipv6" => vec![quote! {
+            utoipa_validate::Ipv6Validator::new()
+        }],
+        _ => vec![],
+    }
+}
+
+/// Creates the validators for a range like `range = "1..=10"` or `range = 1..10`. The start is an
+/// inclusive minimum, the end an exclusive or inclusive maximum depending on the range limits.
+fn create_range_validators(value: Expr) -> Vec<TokenStream> {
+    let range = match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(range),
+            ..
+        }) => match range.parse::<ExprRange>() {
+            Ok(range) => range,
+            Err(_) => {
+                return vec![quote_spanned! {range.span()=>
+                    compile_error!("The range must be a Rust range like \"1..10\" or \"1..=10\".")
+                }];
+            }
+        },
+        Expr::Range(range) => range,
+        value => {
+            return vec![quote_spanned! {value.span()=>
+                compile_error!("The range must be a Rust range like \"1..10\" or \"1..=10\".")
+            }];
+        }
+    };
+
+    let minimum = range.start.map(|start| {
+        let start = create_bound(*start);
+
+        quote! {
+            utoipa_validate::MinimumValidator::new(#start)
+        }
+    });
+    let maximum = range.end.map(|end| {
+        let end = create_bound(*end);
+
+        match range.limits {
+            RangeLimits::HalfOpen(_) => quote! {
+                utoipa_validate::ExclusiveMaximumValidator::new(#end)
+            },
+            RangeLimits::Closed(_) => quote! {
+                utoipa_validate::MaximumValidator::new(#end)
+            },
+        }
+    });
+
+    minimum.into_iter().chain(maximum).collect()
+}
+
+/// Creates the expression for a bound of a range check. String literals are dates like
+/// `2023-01-01` or RFC 3339 date times like `2023-01-01T00:00:00Z` which are parsed here and
+/// converted via the FromBoundLiteral trait.
+fn create_bound(value: Expr) -> TokenStream {
+    match value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(literal),
+            ..
+        }) => match parse_bound_literal(&literal.value()) {
+            Some(bound) => quote! {
+                utoipa_validate::FromBoundLiteral::from_bound_literal(#bound)
+            },
+            None => syn::Error::new_spanned(
+                literal,
+                "Expected a date like \"2023-01-01\" or a date time like \"2023-01-01T00:00:00Z\"",
+            )
+            .to_compile_error(),
+        },
+        value => quote!(#value),
+    }
+}
+
+/// Parses a date or an RFC 3339 date time into a BoundDate or BoundDateTime expression. Returns
+/// None if the literal is malformed or out of range.
+fn parse_bound_literal(literal: &str) -> Option<TokenStream> {
+    fn number<T: std::str::FromStr>(digits: &str, count: usize) -> Option<T> {
+        if digits.len() == count && digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            digits.parse().ok()
+        } else {
+            None
+        }
+    }
+
+    let (date, time) = match literal.split_once(['T', 't']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (literal, None),
+    };
+
+    let mut parts = date.split('-');
+    let year = number::<i32>(parts.next()?, 4)?;
+    let month = number::<u8>(parts.next()?, 2)?;
+    let day = number::<u8>(parts.next()?, 2)?;
+    let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap_year => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    if parts.next().is_some() || !(1..=days).contains(&day) {
+        return None;
+    }
+
+    let date = quote! {
+        utoipa_validate::BoundDate {
+            year: #year,
+            month: #month,
+            day: #day,
+        }
+    };
+
+    let Some(time) = time else {
+        return Some(date);
+    };
+
+    let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+        (time, 0)
+    } else {
+        let index = time.rfind(['+', '-'])?;
+        let (time, offset) = time.split_at(index);
+        let (hours, minutes) = offset[1..].split_once(':')?;
+        let hours = number::<i32>(hours, 2).filter(|hours| *hours < 24)?;
+        let minutes = number::<i32>(minutes, 2).filter(|minutes| *minutes < 60)?;
+        let sign = if offset.starts_with('-') { -1 } else { 1 };
+
+        (time, sign * (hours * 3600 + minutes * 60))
+    };
+
+    let (time, fraction) = match time.split_once('.') {
+        Some((time, fraction)) => (time, Some(fraction)),
+        None => (time, None),
+    };
+    let nanosecond = match fraction {
+        Some(fraction) if (1..=9).contains(&fraction.len()) => {
+            number::<u32>(fraction, fraction.len())? * 10u32.pow(9 - fraction.len() as u32)
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+
+    let mut parts = time.split(':');
+    let hour = number::<u8>(parts.next()?, 2).filter(|hour| *hour < 24)?;
+    let minute = number::<u8>(parts.next()?, 2).filter(|minute| *minute < 60)?;
+    let second = number::<u8>(parts.next()?, 2).filter(|second| *second < 60)?;
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(quote! {
+        utoipa_validate::BoundDateTime {
+            date: #date,
+            hour: #hour,
+            minute: #minute,
+            second: #second,
+            nanosecond: #nanosecond,
+            offset: #offset,
+        }
+    })
+}
+
+/// Creates the validators for a length attribute like `char_length(min = 1, max = 5)`. The names
+/// of the bounds are passed together with the validator types.
+fn create_length_validators(
+    list: MetaList,
+    (max_name, max_validator): (&str, TokenStream),
+    (min_name, min_validator): (&str, TokenStream),
+) -> Vec<TokenStream> {
+    let bounds =
+        match list.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated) {
+            Ok(bounds) => bounds,
+            Err(error) => return vec![error.to_compile_error()],
+        };
+
+    bounds
+        .into_iter()
+        .map(|MetaNameValue { path, value, .. }| {
+            if path.is_ident(max_name) {
+                quote! {
+                    utoipa_validate::#max_validator::new(#value)
+                }
+            } else if path.is_ident(min_name) {
+                quote! {
+                    utoipa_validate::#min_validator::new(#value)
+                }
+            } else {
+                let message = format!("Expected {} or {}", min_name, max_name);
+
+                syn::Error::new_spanned(path, message).to_compile_error()
+            }
+        })
+        .collect()
+}
+
+/// Searches the pattern for constructs that are valid in other regular expression engines (e.g.
+/// PCRE) but not supported by the regex crate. Returns a description of the first found construct.
+fn find_unsupported_regex_construct(pattern: &str) -> Option<&'static str> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut in_class = false;
+    let mut index = 0;
+
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => {
+                match chars.get(index + 1) {
+                    Some('1'..='9') => return Some("a backreference"),
+                    Some('k') if chars.get(index + 2) == Some(&'<') => {
+                        return Some("a backreference")
+                    }
+                    _ => {}
+                }
+
+                index += 1;
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => {
+                let rest = chars[index + 1..].iter().take(3).collect::<String>();
+
+                if rest.starts_with("?=") || rest.starts_with("?!") {
+                    return Some("a lookahead");
+                }
+                if rest.starts_with("?<=") || rest.starts_with("?<!") {
+                    return Some("a lookbehind");
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+/// Options of a field that are given via attributes and do not create validators.
+#[derive(Default)]
+struct FieldOptions {
+    /// Value of `#[validate(skip = ...)]`, `#[validate(skip)]` is short for `skip = true`.
+    skip: Option<bool>,
+    /// Whether serde skips the field during deserialization.
+    serde_skip: bool,
+    /// Name of the field given via `#[serde(rename = "...")]` that is used in error paths. For
+    /// `rename(deserialize = "...")` the deserialization name is used.
+    rename: Option<String>,
+    /// Whether the field is validated as opaque byte string via `#[validate(bytes(...))]`. The
+    /// items of such fields are not validated individually.
+    opaque_bytes: bool,
+    /// Maximum number of errors the field contributes via `#[validate(max_errors = N)]`.
+    max_errors: Option<Expr>,
+    /// Whether the entries of a map are validated ordered by key via `#[validate(sorted)]`.
+    sorted: bool,
+    /// Human readable label of the field given via `#[validate(label = "...")]`.
+    label: Option<String>,
+    /// Message replacing the messages of the schema checks via `#[validate(message = "...")]`.
+    message: Option<LitStr>,
+    /// Whether the errors of the schema checks are warnings via `#[validate(warning)]`.
+    warning: bool,
+    /// Whether max_length and min_length count bytes instead of characters via
+    /// `#[validate(length = "bytes")]`. The default `#[validate(length = "chars")]` counts
+    /// characters.
+    length_in_bytes: bool,
+    /// Errors of invalid option values which are reported instead of the checks of the field.
+    errors: Vec<TokenStream>,
+    /// Normalizers given via `#[validate(trim, lowercase)]` in the order of their appearance.
+    normalizers: Vec<TokenStream>,
+}
+
+impl FieldOptions {
+    fn parse(attributes: &[Attribute]) -> Self {
+        let mut options = FieldOptions::default();
+
+        for attribute in attributes {
+            let is_validate = attribute.path().is_ident("validate");
+            let is_serde = attribute.path().is_ident("serde");
+
+            if !is_validate && !is_serde {
+                continue;
+            }
+
+            let metas = parse_keywords(attribute).unwrap_or_default();
+
+            for meta in metas {
+                match meta {
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Bool(value),
+                                ..
+                            }),
+                        ..
+                    }) if is_validate && path.is_ident("skip") => {
+                        options.skip = Some(value.value);
+                    }
+                    Meta::Path(path) if is_validate && path.is_ident("skip") => {
+                        options.skip = Some(true);
+                    }
+                    Meta::NameValue(MetaNameValue { path, value, .. })
+                        if is_validate && path.is_ident("max_errors") =>
+                    {
+                        options.max_errors = Some(value);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Str(label),
+                                ..
+                            }),
+                        ..
+                    }) if is_validate && path.is_ident("label") => {
+                        options.label = Some(label.value());
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Str(message),
+                                ..
+                            }),
+                        ..
+                    }) if is_validate && path.is_ident("message") => {
+                        options.message = Some(message);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Str(unit),
+                                ..
+                            }),
+                        ..
+                    }) if is_validate && path.is_ident("length") => match unit.value().as_str() {
+                        "bytes" => options.length_in_bytes = true,
+                        "chars" => options.length_in_bytes = false,
+                        _ => options.errors.push(
+                            syn::Error::new_spanned(
+                                unit,
+                                "The length unit must be \"bytes\" or \"chars\".",
+                            )
+                            .to_compile_error(),
+                        ),
+                    },
+                    Meta::Path(path) if is_validate && path.is_ident("warning") => {
+                        options.warning = true;
+                    }
+                    Meta::Path(path) if is_validate && path.is_ident("sorted") => {
+                        options.sorted = true;
+                    }
+                    Meta::Path(path) if is_validate && path.is_ident("trim") => {
+                        options
+                            .normalizers
+                            .push(quote!(utoipa_validate::TrimNormalizer {}));
+                    }
+                    Meta::Path(path) if is_validate && path.is_ident("lowercase") => {
+                        options
+                            .normalizers
+                            .push(quote!(utoipa_validate::LowercaseNormalizer {}));
+                    }
+                    Meta::List(list) if is_validate && list.path.is_ident("bytes") => {
+                        options.opaque_bytes = true;
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        value:
+                            Expr::Lit(ExprLit {
+                                lit: Lit::Str(name),
+                                ..
+                            }),
+                        ..
+                    }) if is_serde && path.is_ident("rename") => {
+                        options.rename = Some(name.value());
+                    }
+                    Meta::List(list) if is_serde && list.path.is_ident("rename") => {
+                        let names = list
+                            .parse_args_with(
+                                Punctuated::<MetaNameValue, Token![,]>::parse_terminated,
+                            )
+                            .unwrap_or_default();
+
+                        for name in names {
+                            if let Expr::Lit(ExprLit {
+                                lit: Lit::Str(value),
+                                ..
+                            }) = name.value
+                            {
+                                if name.path.is_ident("deserialize") {
+                                    options.rename = Some(value.value());
+                                }
+                            }
+                        }
+                    }
+                    Meta::Path(path)
+                        if is_serde
+                            && (path.is_ident("skip") || path.is_ident("skip_deserializing")) =>
+                    {
+                        options.serde_skip = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        options
+    }
+
+    /// Returns whether the validation of the field is skipped. Fields that are skipped by serde
+    /// during deserialization are not validated unless `#[validate(skip = false)]` is given.
+    fn is_skipped(&self) -> bool {
+        self.skip.unwrap_or(self.serde_skip)
+    }
+}
+
+/// Returns the name of the field in error paths. Like serde, an explicit rename wins over the
+/// rename_all rule of the container which only applies to named fields.
+fn field_path_name(field: &Field, name: String, rename_all: Option<&str>) -> String {
+    match (FieldOptions::parse(&field.attrs).rename, rename_all) {
+        (Some(rename), _) => rename,
+        (None, Some(rule)) if field.ident.is_some() => convert_case(&name, rule),
+        (None, _) => name,
+    }
+}
+
+/// Returns whether the container is annotated with `#[validate(flag)]`.
+fn parse_container_flag(attributes: &[Attribute], flag: &str) -> bool {
+    attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("validate"))
+        .flat_map(|attribute| parse_keywords(attribute).unwrap_or_default())
+        .any(|meta| matches!(meta, Meta::Path(path) if path.is_ident(flag)))
+}
+
+/// Returns the content key of adjacently tagged enums, i.e. containers annotated with
+/// `#[serde(tag = "...", content = "...")]`.
+fn parse_adjacent_content(attributes: &[Attribute]) -> Option<String> {
+    let mut tag = None;
+    let mut content = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("serde") {
+            continue;
+        }
+
+        let metas = attribute
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .unwrap_or_default();
+
+        for meta in metas {
+            if let Meta::NameValue(MetaNameValue {
+                path,
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(value),
+                        ..
+                    }),
+                ..
+            }) = meta
+            {
+                if path.is_ident("tag") {
+                    tag = Some(value.value());
+                } else if path.is_ident("content") {
+                    content = Some(value.value());
+                }
+            }
+        }
+    }
+
+    tag.and(content)
+}
+
+/// Returns the rule of `#[serde(rename_all = "...")]` or `#[serde(rename_all(deserialize = "..."))]`
+/// of a container.
+fn parse_rename_all(attributes: &[Attribute]) -> Option<String> {
+    let mut rename_all = None;
+
+    for attribute in attributes {
+        if !attribute.path().is_ident("serde") {
+            continue;
+        }
+
+        let metas = attribute
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .unwrap_or_default();
+
+        for meta in metas {
+            match meta {
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    value:
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(rule),
+                            ..
+                        }),
+                    ..
+                }) if path.is_ident("rename_all") => {
+                    rename_all = Some(rule.value());
+                }
+                Meta::List(list) if list.path.is_ident("rename_all") => {
+                    let rules = list
+                        .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)
+                        .unwrap_or_default();
+
+                    for rule in rules {
+                        if let Expr::Lit(ExprLit {
+                            lit: Lit::Str(value),
+                            ..
+                        }) = rule.value
+                        {
+                            if rule.path.is_ident("deserialize") {
+                                rename_all = Some(value.value());
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    rename_all
+}
+
+/// Converts a snake case field name according to a serde rename_all rule. Unknown rules keep the
+/// name.
+fn convert_case(name: &str, rule: &str) -> String {
+    let pascal_case = || {
+        name.split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect::<String>()
+    };
+
+    match rule {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" | "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "PascalCase" => pascal_case(),
+        "camelCase" => {
+            let pascal_case = pascal_case();
+            let mut chars = pascal_case.chars();
+
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.to_uppercase().replace('_', "-"),
+        _ => name.to_owned(),
+    }
+}
+
+/// Converts a pascal case variant name according to a serde rename_all rule. Unknown rules keep
+/// the name.
+fn convert_variant_case(name: &str, rule: &str) -> String {
+    let snake_case = || {
+        name.chars()
+            .enumerate()
+            .flat_map(|(index, c)| {
+                let separator = (index > 0 && c.is_uppercase()).then_some('_');
+
+                separator.into_iter().chain(c.to_lowercase())
+            })
+            .collect::<String>()
+    };
+
+    match rule {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "camelCase" => {
+            let mut chars = name.chars();
+
+            match chars.next() {
+                Some(first) => first.to_lowercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        }
+        "snake_case" => snake_case(),
+        "SCREAMING_SNAKE_CASE" => snake_case().to_uppercase(),
+        "kebab-case" => snake_case().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake_case().to_uppercase().replace('_', "-"),
+        _ => name.to_owned(),
+    }
+}
+
+/// Returns whether the type is a primitive type whose default validator never reports errors. The
+/// recursive validation of such fields can be omitted. Only bare names and paths into the standard
+/// library are considered, e.g. `crate::model::String` may be a type with own validation.
+fn is_primitive(t: &Type) -> bool {
+    match t {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Path(path) if path.qself.is_none() => {
+            let segments = &path.path.segments;
+            let is_std = segments.len() > 1
+                && ["std", "core", "alloc"]
+                    .iter()
+                    .any(|krate| segments[0].ident == krate);
+            let is_bare = segments.len() == 1 && path.path.leading_colon.is_none();
+            let segment = segments.last().expect("Expected at least one segment");
+
+            (is_std || is_bare)
+                && segments.iter().all(|segment| segment.arguments.is_empty())
+                && matches!(
+                    segment.ident.to_string().as_str(),
+                    "bool"
+                        | "i8"
+                        | "i16"
+                        | "i32"
+                        | "i64"
+                        | "isize"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "usize"
+                        | "f32"
+                        | "f64"
+                        | "char"
+                        | "String"
+                )
+        }
+        _ => false,
+    }
+}
+
+/// Kind of the generated checks.
+#[derive(Clone, Copy, PartialEq)]
+enum CheckMode {
+    /// Collect all errors.
+    All,
+    /// Return the first error via `ControlFlow::Break`.
+    FailFast,
+    /// Collect all errors of the values that differ from the `previous` value.
+    Changed,
+}
+
+impl CheckMode {
+    /// Wraps the checks such that they only run if the value differs from the previous value in
+    /// the Changed mode.
+    fn only_changed(
+        self,
+        checks: TokenStream,
+        value: TokenStream,
+        previous: TokenStream,
+    ) -> TokenStream {
+        if self != CheckMode::Changed || checks.is_empty() {
+            return checks;
+        }
+
+        quote! {
+            if #value != #previous {
+                #checks
+            }
+        }
+    }
+}
+
+/// Container types that are looked through when applying item keywords.
+enum Wrapper {
+    Option,
+    Patch,
+    Vec,
+    Array,
+    /// Arithmetic wrappers like `Wrapping<T>` that are validated like the wrapped number.
+    Number,
+}
+
+/// Returns the nested Option, Vec, array and number wrappers of the type from the outside in, e.g.
+/// `[Option, Vec, Option]` for `Option<Vec<Option<i32>>>`.
+fn find_wrappers(t: &Type) -> Vec<Wrapper> {
+    let mut wrappers = Vec::new();
+    let mut t = t;
+
+    loop {
+        let path = match t {
+            Type::Path(path) => path,
+            Type::Array(array) => {
+                wrappers.push(Wrapper::Array);
+                t = &array.elem;
+                continue;
+            }
+            _ => break,
+        };
+        let segment = path
+            .path
+            .segments
+            .last()
+            .expect("Expected at least one segment");
+        let wrapper = if segment.ident == "Option" {
+            Wrapper::Option
+        } else if segment.ident == "Patch" {
+            Wrapper::Patch
+        } else if segment.ident == "Vec" {
+            Wrapper::Vec
+        } else if segment.ident == "Wrapping" || segment.ident == "Saturating" {
+            Wrapper::Number
+        } else {
+            break;
+        };
+
+        wrappers.push(wrapper);
+
+        match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                Some(GenericArgument::Type(inner)) => t = inner,
+                _ => break,
+            },
+            _ => break,
+        }
+    }
+
+    wrappers
+}
+
 fn generate_field_name(index: usize) -> Ident {
     let s = format!("_{}", index);
     Ident::new(&s, Span::call_site())
@@ -1,10 +1,49 @@
+#[cfg(feature = "regex")]
 use regex::Regex;
+use std::any::Any;
+#[cfg(feature = "regex")]
+use std::borrow::Borrow;
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
-use std::ops::Rem;
+use std::ops::{ControlFlow, Range, RangeInclusive};
+use std::rc::Rc;
+use std::sync::Arc;
 
 pub use utoipa_validate_gen::*;
 
+/// Re-export of the regex crate used by the code generated for the 'pattern' keyword.
+#[cfg(feature = "regex")]
+pub use regex;
+
+/// Used by the code generated for the 'pattern' keywords to report a missing regex feature. Items
+/// prefixed with `@item` are dropped without the feature.
+#[cfg(feature = "regex")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_regex {
+    (@item $($item:tt)*) => {
+        $($item)*
+    };
+    ($validator:expr) => {
+        $validator
+    };
+}
+
+#[cfg(not(feature = "regex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __require_regex {
+    (@item $($item:tt)*) => {};
+    ($validator:expr) => {
+        compile_error!("The pattern keywords require the regex feature of utoipa-validate")
+    };
+}
+
 /// Path to a value that is validated.
 pub enum ValidationPath<'a, 'b> {
     Root,
@@ -16,12 +55,47 @@ pub enum ValidationPath<'a, 'b> {
         parent: &'b ValidationPath<'a, 'a>,
         index: usize,
     },
+    /// The entry of a map with the given key. Used for the value and the key of the entry.
+    Key {
+        parent: &'b ValidationPath<'a, 'a>,
+        key: &'a str,
+    },
+}
+
+impl ValidationPath<'_, '_> {
+    /// Returns the index of the innermost collection item on this path or None if the path does
+    /// not point into a collection.
+    pub fn index(&self) -> Option<usize> {
+        match self {
+            ValidationPath::Root => None,
+            ValidationPath::Field { parent, .. } | ValidationPath::Key { parent, .. } => {
+                parent.index()
+            }
+            ValidationPath::Item { index, .. } => Some(*index),
+        }
+    }
+
+    /// Returns the number of segments from the root, e.g. 3 for `a.b[0]`.
+    pub fn depth(&self) -> usize {
+        match self {
+            ValidationPath::Root => 0,
+            ValidationPath::Field { parent, .. }
+            | ValidationPath::Item { parent, .. }
+            | ValidationPath::Key { parent, .. } => parent.depth() + 1,
+        }
+    }
 }
 
 impl Display for ValidationPath<'_, '_> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             ValidationPath::Root => Ok(()),
+            // Names that could be mistaken for other segments are quoted like keys.
+            ValidationPath::Field { parent, name }
+                if name.is_empty() || name.contains(['.', '[', ']', '"', '\\']) =>
+            {
+                write_key(f, parent, name)
+            }
             ValidationPath::Field {
                 parent: ValidationPath::Root,
                 name,
@@ -32,13 +106,61 @@ impl Display for ValidationPath<'_, '_> {
                 index,
             } => write!(f, "[{}]", index),
             ValidationPath::Item { parent, index } => write!(f, "{}[{}]", parent, index),
+            ValidationPath::Key { parent, key } => write_key(f, parent, key),
+        }
+    }
+}
+
+fn write_key(f: &mut Formatter<'_>, parent: &ValidationPath, key: &str) -> std::fmt::Result {
+    write!(f, "{}[\"", parent)?;
+
+    for c in key.chars() {
+        if matches!(c, '"' | '\\') {
+            write!(f, "\\")?;
+        }
+
+        write!(f, "{}", c)?;
+    }
+
+    write!(f, "\"]")
+}
+
+/// Owned segment of the path of a [ValidationError], see [ValidationError::path_segments].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Item(usize),
+    Key(String),
+}
+
+impl PathSegment {
+    /// Calls the function with the [ValidationPath] of the passed segments below the parent.
+    pub fn with_path<R>(
+        parent: &ValidationPath,
+        segments: &[PathSegment],
+        function: impl FnOnce(&ValidationPath) -> R,
+    ) -> R {
+        match segments {
+            [] => function(parent),
+            [segment, rest @ ..] => {
+                let path = match segment {
+                    PathSegment::Field(name) => ValidationPath::Field { parent, name },
+                    PathSegment::Item(index) => ValidationPath::Item {
+                        parent,
+                        index: *index,
+                    },
+                    PathSegment::Key(key) => ValidationPath::Key { parent, key },
+                };
+
+                Self::with_path(&path, rest, function)
+            }
         }
     }
 }
 
 /// Category for validation errors that can be used to differentiate between different errors
-/// independent of the error message.
-#[derive(Debug, Clone, Eq, PartialEq)]
+/// independent of the error message. Categories of type Other are compared and hashed by their tag.
+#[derive(Debug, Clone)]
 pub enum ValidationErrorCategory {
     ExclusiveMaximum,
     ExclusiveMinimum,
@@ -46,33 +168,585 @@ pub enum ValidationErrorCategory {
     Minimum,
     MaxItems,
     MinItems,
+    /// An item is equal to an earlier item. The actual value is the index of the duplicate, the
+    /// expected value the index of the earlier item.
+    UniqueItems,
+    MaxProperties,
+    MinProperties,
     MaxLength,
     MinLength,
+    MaxByteLength,
+    MinByteLength,
     MultipleOf,
+    Const,
+    Enumeration,
+    /// A float is NaN or infinite.
+    Finite,
+    /// A string contains non-ASCII characters. The actual value is the first non-ASCII character.
+    Ascii,
     Pattern,
+    Format {
+        /// Name of the format, e.g. "url".
+        format: &'static str,
+    },
+    UrlScheme,
+    RangeOrder,
+    Required,
+    /// A value is accepted by the validator of a `not` constraint.
+    Not,
     Other {
         /// Tag that can be used to identify the error category.
         tag: &'static str,
         /// Display function to get the error string.
         display: fn(error: &ValidationError, f: &mut Formatter<'_>) -> std::fmt::Result,
+        /// Optional structured data of custom validators, see [ValidationError::downcast_detail].
+        /// The detail is not compared, hashed or serialized.
+        detail: Option<Arc<dyn Any + Send + Sync>>,
     },
 }
 
+impl ValidationErrorCategory {
+    /// Returns the name of this category in snake case, e.g. "max_length". Other categories return
+    /// their tag.
+    pub fn tag(&self) -> &'static str {
+        match self {
+            ValidationErrorCategory::ExclusiveMaximum => "exclusive_maximum",
+            ValidationErrorCategory::ExclusiveMinimum => "exclusive_minimum",
+            ValidationErrorCategory::Maximum => "maximum",
+            ValidationErrorCategory::Minimum => "minimum",
+            ValidationErrorCategory::MaxItems => "max_items",
+            ValidationErrorCategory::MinItems => "min_items",
+            ValidationErrorCategory::UniqueItems => "unique_items",
+            ValidationErrorCategory::MaxProperties => "max_properties",
+            ValidationErrorCategory::MinProperties => "min_properties",
+            ValidationErrorCategory::MaxLength => "max_length",
+            ValidationErrorCategory::MinLength => "min_length",
+            ValidationErrorCategory::MaxByteLength => "max_byte_length",
+            ValidationErrorCategory::MinByteLength => "min_byte_length",
+            ValidationErrorCategory::MultipleOf => "multiple_of",
+            ValidationErrorCategory::Const => "const",
+            ValidationErrorCategory::Enumeration => "enumeration",
+            ValidationErrorCategory::Finite => "finite",
+            ValidationErrorCategory::Ascii => "ascii",
+            ValidationErrorCategory::Pattern => "pattern",
+            ValidationErrorCategory::Format { .. } => "format",
+            ValidationErrorCategory::UrlScheme => "url_scheme",
+            ValidationErrorCategory::RangeOrder => "range_order",
+            ValidationErrorCategory::Required => "required",
+            ValidationErrorCategory::Not => "not",
+            ValidationErrorCategory::Other { tag, .. } => tag,
+        }
+    }
+
+    /// Returns the values that identify this category, i.e. the rank of the variant and the format
+    /// or tag. The display function of Other categories is not part of it.
+    fn identity(&self) -> (usize, &'static str) {
+        match self {
+            ValidationErrorCategory::ExclusiveMaximum => (0, ""),
+            ValidationErrorCategory::ExclusiveMinimum => (1, ""),
+            ValidationErrorCategory::Maximum => (2, ""),
+            ValidationErrorCategory::Minimum => (3, ""),
+            ValidationErrorCategory::MaxItems => (4, ""),
+            ValidationErrorCategory::MinItems => (5, ""),
+            ValidationErrorCategory::UniqueItems => (6, ""),
+            ValidationErrorCategory::MaxProperties => (7, ""),
+            ValidationErrorCategory::MinProperties => (8, ""),
+            ValidationErrorCategory::MaxLength => (9, ""),
+            ValidationErrorCategory::MinLength => (10, ""),
+            ValidationErrorCategory::MaxByteLength => (11, ""),
+            ValidationErrorCategory::MinByteLength => (12, ""),
+            ValidationErrorCategory::MultipleOf => (13, ""),
+            ValidationErrorCategory::Const => (14, ""),
+            ValidationErrorCategory::Enumeration => (15, ""),
+            ValidationErrorCategory::Finite => (16, ""),
+            ValidationErrorCategory::Ascii => (17, ""),
+            ValidationErrorCategory::Pattern => (18, ""),
+            ValidationErrorCategory::Format { format } => (19, format),
+            ValidationErrorCategory::UrlScheme => (20, ""),
+            ValidationErrorCategory::RangeOrder => (21, ""),
+            ValidationErrorCategory::Required => (22, ""),
+            ValidationErrorCategory::Not => (23, ""),
+            ValidationErrorCategory::Other { tag, .. } => (24, tag),
+        }
+    }
+}
+
+impl PartialEq for ValidationErrorCategory {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for ValidationErrorCategory {}
+
+/// Categories are ordered by their declaration order. Formats and Other categories are ordered by
+/// their format and tag respectively.
+impl PartialOrd for ValidationErrorCategory {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ValidationErrorCategory {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.identity().cmp(&other.identity())
+    }
+}
+
+impl Hash for ValidationErrorCategory {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+/// Severity of a validation error.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord, Default)]
+pub enum Severity {
+    /// The value is invalid.
+    #[default]
+    Error,
+    /// The value is valid but questionable, e.g. given via `#[validate(warning)]`.
+    Warning,
+}
+
 /// Struct describing an error during validation.
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct ValidationError {
     /// Category of the error.
     pub category: ValidationErrorCategory,
     /// Path to the value that caused the error.
     pub path: String,
+    /// Index of the collection item that contains the value that caused the error. None if the
+    /// value is not part of a collection.
+    pub index: Option<usize>,
     /// The actual value.
     pub actual: String,
     /// The expected value. The meaning of this value depends on the category.
     pub expected: String,
+    /// Message that replaces the generated message of the category, e.g. given via
+    /// `#[validate(message = "...")]`.
+    pub custom_message: Option<String>,
+    /// Severity of the error. Warnings are reported like errors by validate() but do not fail
+    /// validate_owned_with_warnings().
+    pub severity: Severity,
+}
+
+/// Errors are ordered by their path first and by their category afterwards.
+impl PartialOrd for ValidationError {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ValidationError {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.path
+            .cmp(&other.path)
+            .then_with(|| self.category.cmp(&other.category))
+            .then_with(|| self.index.cmp(&other.index))
+            .then_with(|| self.actual.cmp(&other.actual))
+            .then_with(|| self.expected.cmp(&other.expected))
+            .then_with(|| self.custom_message.cmp(&other.custom_message))
+            .then_with(|| self.severity.cmp(&other.severity))
+    }
+}
+
+impl ValidationError {
+    /// Returns a copy of this error whose path is re-rooted below the passed prefix, e.g. the
+    /// prefix "b" turns the path "c" into "b.c".
+    pub fn with_path_prefix(&self, prefix: &str) -> ValidationError {
+        let path = if self.path.is_empty() {
+            prefix.to_owned()
+        } else if prefix.is_empty() || self.path.starts_with('[') {
+            format!("{}{}", prefix, self.path)
+        } else {
+            format!("{}.{}", prefix, self.path)
+        };
+
+        ValidationError {
+            path,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the segments of the path of this error, i.e. the inverse of the Display
+    /// implementation of [ValidationPath]. Map keys and field names containing `.`, `[` or `]` are
+    /// quoted, e.g. `map["1"]`, such that they are not mistaken for other segments. Quoted field
+    /// names are returned as [PathSegment::Key].
+    pub fn path_segments(&self) -> Vec<PathSegment> {
+        let mut segments = Vec::new();
+        let mut name = String::new();
+        let mut chars = self.path.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '.' | '[' if !name.is_empty() => {
+                    segments.push(PathSegment::Field(std::mem::take(&mut name)));
+                }
+                _ => {}
+            }
+
+            match c {
+                '.' => {}
+                '[' if chars.next_if_eq(&'"').is_some() => {
+                    let mut key = String::new();
+
+                    while let Some(c) = chars.next() {
+                        match c {
+                            '\\' => key.extend(chars.next()),
+                            '"' => break,
+                            c => key.push(c),
+                        }
+                    }
+
+                    chars.next_if_eq(&']');
+                    segments.push(PathSegment::Key(key));
+                }
+                '[' => {
+                    let index = chars.by_ref().take_while(|c| *c != ']').collect::<String>();
+
+                    segments.push(match index.parse() {
+                        Ok(index) => PathSegment::Item(index),
+                        Err(_) => PathSegment::Field(index),
+                    });
+                }
+                c => name.push(c),
+            }
+        }
+
+        if !name.is_empty() {
+            segments.push(PathSegment::Field(name));
+        }
+
+        segments
+    }
+
+    /// Returns the human readable message of this error like the Display implementation.
+    pub fn message(&self) -> String {
+        self.to_string()
+    }
+
+    /// Returns a stable machine readable code for the category of this error, e.g. "maximum".
+    /// Errors of Other categories return their tag.
+    pub fn code(&self) -> &'static str {
+        self.category.tag()
+    }
+
+    /// Returns the detail of errors of the [ValidationErrorCategory::Other] category if it is of
+    /// type T.
+    pub fn downcast_detail<T: Any>(&self) -> Option<&T> {
+        match &self.category {
+            ValidationErrorCategory::Other {
+                detail: Some(detail),
+                ..
+            } => detail.downcast_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the source of the regular expression for errors of the
+    /// [ValidationErrorCategory::Pattern] category and None otherwise.
+    pub fn pattern_source(&self) -> Option<&str> {
+        match self.category {
+            ValidationErrorCategory::Pattern => Some(&self.expected),
+            _ => None,
+        }
+    }
+
+    /// Returns the nesting depth of the path of this error. Errors of the root value have depth 0,
+    /// each field, item or key access increases it by one. Map keys count as one segment no
+    /// matter which characters they contain.
+    pub fn depth(&self) -> usize {
+        self.path_segments().len()
+    }
+
+    /// Returns a displayable version of this error whose message renders item indices 1-based,
+    /// e.g. `items[3]` for the third item. The structured path is not changed. Map keys are kept.
+    pub fn one_based(&self) -> OneBasedValidationError<'_> {
+        OneBasedValidationError { error: self }
+    }
+
+    /// Returns a displayable version of this error whose message uses the label of the field
+    /// instead of the path, e.g. "Email: ..." instead of "email: ...". The labels are pairs of path
+    /// and label like returned by the `labels()` method of derived validators. The path is kept if
+    /// no label matches.
+    pub fn labeled<'a>(&'a self, labels: &'a [(&str, &str)]) -> LabeledValidationError<'a> {
+        LabeledValidationError {
+            error: self,
+            labels,
+        }
+    }
+
+    /// Counts the passed errors per category. The categories are identified by their tag.
+    pub fn summarize(errors: &[ValidationError]) -> BTreeMap<&'static str, usize> {
+        let mut summary = BTreeMap::new();
+
+        for error in errors {
+            *summary.entry(error.category.tag()).or_insert(0) += 1;
+        }
+
+        summary
+    }
+
+    /// Re-roots all passed errors below the passed prefix. See with_path_prefix().
+    pub fn prefix_paths(errors: &[ValidationError], prefix: &str) -> Vec<ValidationError> {
+        errors
+            .iter()
+            .map(|error| error.with_path_prefix(prefix))
+            .collect()
+    }
+}
+
+/// Serializes the category as its tag, e.g. "max_length".
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValidationErrorCategory {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.tag())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ValidationError {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("ValidationError", 7)?;
+        state.serialize_field("category", &self.category)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("index", &self.index)?;
+        state.serialize_field("actual", &self.actual)?;
+        state.serialize_field("expected", &self.expected)?;
+        match &self.custom_message {
+            Some(message) => state.serialize_field("message", message)?,
+            None => state.skip_field("message")?,
+        }
+        match self.severity {
+            Severity::Error => state.skip_field("severity")?,
+            Severity::Warning => state.serialize_field("severity", "warning")?,
+        }
+        state.end()
+    }
+}
+
+/// A list of validation errors that implements [std::error::Error], e.g. to use it with `?` in
+/// functions returning `Box<dyn Error>`. The messages of the errors are displayed line by line.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ValidationErrors(pub Vec<ValidationError>);
+
+impl Display for ValidationErrors {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "{}", error)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl ValidationErrors {
+    /// Groups the errors by their path, e.g. for form-style APIs that show the errors next to the
+    /// fields. The paths are sorted, the errors of a path keep their order.
+    pub fn group_by_path(self) -> BTreeMap<String, Vec<ValidationError>> {
+        let mut groups = BTreeMap::<String, Vec<ValidationError>>::new();
+
+        for error in self.0 {
+            groups.entry(error.path.clone()).or_default().push(error);
+        }
+
+        groups
+    }
+}
+
+impl From<Vec<ValidationError>> for ValidationErrors {
+    fn from(errors: Vec<ValidationError>) -> Self {
+        Self(errors)
+    }
+}
+
+/// Converts the error for code that consumes errors of the `validator` crate. The categories are
+/// mapped to the codes of the `validator` crate where one exists, e.g. "length" for max_length or
+/// "range" for minimum, and to their tag otherwise. The message is the message of the error, the
+/// params contain the actual value as "value" and the expected value as "min", "max" or
+/// "expected" depending on the category.
+#[cfg(feature = "validator")]
+impl From<&ValidationError> for validator::ValidationError {
+    fn from(error: &ValidationError) -> Self {
+        let (code, expected) = match &error.category {
+            ValidationErrorCategory::MinLength
+            | ValidationErrorCategory::MinByteLength
+            | ValidationErrorCategory::MinItems => ("length", "min"),
+            ValidationErrorCategory::MaxLength
+            | ValidationErrorCategory::MaxByteLength
+            | ValidationErrorCategory::MaxItems => ("length", "max"),
+            ValidationErrorCategory::Minimum | ValidationErrorCategory::ExclusiveMinimum => {
+                ("range", "min")
+            }
+            ValidationErrorCategory::Maximum | ValidationErrorCategory::ExclusiveMaximum => {
+                ("range", "max")
+            }
+            ValidationErrorCategory::Pattern => ("regex", "expected"),
+            ValidationErrorCategory::Format { format: "email" } => ("email", "expected"),
+            ValidationErrorCategory::Format { format: "url" }
+            | ValidationErrorCategory::UrlScheme => ("url", "expected"),
+            category => (category.tag(), "expected"),
+        };
+
+        let mut result =
+            validator::ValidationError::new(code).with_message(Cow::Owned(error.message()));
+        result.add_param(Cow::Borrowed("value"), &error.actual);
+        result.add_param(Cow::Borrowed(expected), &error.expected);
+
+        result
+    }
+}
+
+/// Converts the errors into the nested structure of the `validator` crate to ease migrating from
+/// it. Fields become struct errors, items become list errors and map keys are handled like fields,
+/// also if they contain `.` or `[`, see [ValidationError::path_segments].
+/// Errors of the root value are stored under "__all__". The `validator` crate can not represent
+/// errors of a field and of its nested values at once, so nested errors of a field with own errors
+/// are added to the errors of the field. Their messages contain the full path.
+#[cfg(feature = "validator")]
+impl From<ValidationErrors> for validator::ValidationErrors {
+    fn from(errors: ValidationErrors) -> Self {
+        fn insert(
+            errors: &mut validator::ValidationErrors,
+            segments: &[PathSegment],
+            error: validator::ValidationError,
+        ) {
+            use validator::ValidationErrorsKind;
+
+            let (name, rest) = match segments {
+                [] => ("__all__".to_owned(), segments),
+                [PathSegment::Field(name) | PathSegment::Key(name), rest @ ..] => {
+                    (name.clone(), rest)
+                }
+                [PathSegment::Item(index), rest @ ..] => (index.to_string(), rest),
+            };
+            let compatible = matches!(
+                (errors.0.get(name.as_str()), rest),
+                (None | Some(ValidationErrorsKind::Field(_)), _)
+                    | (
+                        Some(ValidationErrorsKind::Struct(_)),
+                        [PathSegment::Field(_) | PathSegment::Key(_), ..]
+                    )
+                    | (
+                        Some(ValidationErrorsKind::List(_)),
+                        [PathSegment::Item(_), ..]
+                    )
+            );
+
+            // E.g. map keys that are numeric for some entries only.
+            if !compatible {
+                return insert(errors, &[], error);
+            }
+
+            let kind = errors
+                .0
+                .entry(Cow::Owned(name))
+                .or_insert_with(|| match rest {
+                    [] => ValidationErrorsKind::Field(Vec::new()),
+                    [PathSegment::Field(_) | PathSegment::Key(_), ..] => {
+                        ValidationErrorsKind::Struct(Box::new(validator::ValidationErrors::new()))
+                    }
+                    [PathSegment::Item(_), ..] => ValidationErrorsKind::List(BTreeMap::new()),
+                });
+
+            match (kind, rest) {
+                (ValidationErrorsKind::Field(field_errors), _) => field_errors.push(error),
+                (ValidationErrorsKind::Struct(nested), _) => insert(nested, rest, error),
+                (ValidationErrorsKind::List(items), [PathSegment::Item(index), rest @ ..]) => {
+                    let item = items
+                        .entry(*index)
+                        .or_insert_with(|| Box::new(validator::ValidationErrors::new()));
+
+                    insert(item, rest, error);
+                }
+                (ValidationErrorsKind::List(_), _) => unreachable!("Checked to be compatible"),
+            }
+        }
+
+        // Shallow errors first such that fields with own errors are stored as field errors.
+        let mut errors = errors.0;
+        errors.sort_by_key(ValidationError::depth);
+
+        let mut result = validator::ValidationErrors::new();
+
+        for error in &errors {
+            insert(&mut result, &error.path_segments(), error.into());
+        }
+
+        result
+    }
+}
+
+/// A validation error whose message renders item indices 1-based. See
+/// [ValidationError::one_based].
+pub struct OneBasedValidationError<'a> {
+    error: &'a ValidationError,
+}
+
+impl Display for OneBasedValidationError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let segments = self
+            .error
+            .path_segments()
+            .into_iter()
+            .map(|segment| match segment {
+                PathSegment::Item(index) => PathSegment::Item(index + 1),
+                segment => segment,
+            })
+            .collect::<Vec<_>>();
+        let path =
+            PathSegment::with_path(&ValidationPath::Root, &segments, |path| path.to_string());
+
+        let error = ValidationError {
+            path,
+            ..self.error.clone()
+        };
+
+        write!(f, "{}", error)
+    }
+}
+
+/// A validation error whose message uses the label of the field. See [ValidationError::labeled].
+pub struct LabeledValidationError<'a> {
+    error: &'a ValidationError,
+    labels: &'a [(&'a str, &'a str)],
+}
+
+impl Display for LabeledValidationError<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self
+            .labels
+            .iter()
+            .find(|(path, _)| *path == self.error.path)
+        {
+            Some((_, label)) => {
+                let error = ValidationError {
+                    path: label.to_string(),
+                    ..self.error.clone()
+                };
+
+                write!(f, "{}", error)
+            }
+            None => write!(f, "{}", self.error),
+        }
+    }
 }
 
 impl Display for ValidationError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(message) = &self.custom_message {
+            return write!(f, "{}", message);
+        }
+
         match &self.category {
             ValidationErrorCategory::ExclusiveMaximum => write!(
                 f,
@@ -104,6 +778,21 @@ impl Display for ValidationError {
                 "{}: Must have at least {} items but has {}",
                 self.path, self.expected, self.actual
             ),
+            ValidationErrorCategory::UniqueItems => write!(
+                f,
+                "{}: Must have unique items but item {} is equal to item {}",
+                self.path, self.actual, self.expected
+            ),
+            ValidationErrorCategory::MaxProperties => write!(
+                f,
+                "{}: Must have at most {} properties but has {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::MinProperties => write!(
+                f,
+                "{}: Must have at least {} properties but has {}",
+                self.path, self.expected, self.actual
+            ),
             ValidationErrorCategory::MaxLength => write!(
                 f,
                 "{}: Must have at most {} characters but has {}",
@@ -114,67 +803,554 @@ impl Display for ValidationError {
                 "{}: Must have at least {} characters but has {}",
                 self.path, self.expected, self.actual
             ),
+            ValidationErrorCategory::MaxByteLength => write!(
+                f,
+                "{}: Must have at most {} bytes but has {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::MinByteLength => write!(
+                f,
+                "{}: Must have at least {} bytes but has {}",
+                self.path, self.expected, self.actual
+            ),
             ValidationErrorCategory::MultipleOf => write!(
                 f,
                 "{}: Must be a multiple of {} but is {}",
                 self.path, self.expected, self.actual
             ),
+            ValidationErrorCategory::Const => write!(
+                f,
+                "{}: Must be equal to {} but is {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::Enumeration => write!(
+                f,
+                "{}: Must be one of {} but is {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::Finite => {
+                write!(
+                    f,
+                    "{}: Must be a finite number but is {}",
+                    self.path, self.actual
+                )
+            }
+            ValidationErrorCategory::Ascii => write!(
+                f,
+                "{}: Must only contain ASCII characters but contains {}",
+                self.path, self.actual
+            ),
             ValidationErrorCategory::Pattern => write!(
                 f,
                 "{}: Must match the regular expression {} but is {}",
                 self.path, self.expected, self.actual
             ),
-            ValidationErrorCategory::Other { tag, display } => {
-                let _ = tag;
-
-                display(self, f)
-            }
+            ValidationErrorCategory::Format { format } => write!(
+                f,
+                "{}: Must be a valid {} but is {}",
+                self.path, format, self.actual
+            ),
+            ValidationErrorCategory::UrlScheme => write!(
+                f,
+                "{}: Must use one of the URL schemes {} but uses {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::RangeOrder => write!(
+                f,
+                "{}: Must have a start less than or equal to the end {} but has {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::Required => write!(f, "{}: Is required", self.path),
+            ValidationErrorCategory::Not => write!(
+                f,
+                "{}: Must not be {} but is {}",
+                self.path, self.expected, self.actual
+            ),
+            ValidationErrorCategory::Other { display, .. } => display(self, f),
         }
     }
 }
 
-/// A validator for type T.
-pub trait Validator<T> {
-    /// Validate the passed value stored at the passed path. Errors are added to the errors vector.
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>);
+/// Receiver of the errors found by validators, e.g. a vector collecting them or a visitor, see
+/// [Validatable::validate_visit].
+pub trait ErrorSink {
+    /// Receives the next validation error.
+    fn push(&mut self, error: ValidationError);
 }
 
-pub trait Validatable: Sized {
-    /// Default validator for values of this type.
-    type DefaultValidator: Validator<Self> + Default;
+impl ErrorSink for Vec<ValidationError> {
+    fn push(&mut self, error: ValidationError) {
+        Vec::push(self, error);
+    }
+}
 
-    /// Validate this value using the default validator.
-    fn validate(&self) -> Result<(), Vec<ValidationError>> {
-        self.validate_with(&Self::DefaultValidator::default())
+impl<F> ErrorSink for F
+where
+    F: FnMut(&ValidationError),
+{
+    fn push(&mut self, error: ValidationError) {
+        self(&error);
     }
+}
 
-    /// Validate this instance with the given validator.
-    fn validate_with<V>(&self, validator: &V) -> Result<(), Vec<ValidationError>>
-    where
-        V: Validator<Self>,
-    {
-        let mut errors = Vec::new();
-        validator.validate(&ValidationPath::Root, self, &mut errors);
+/// Error sink that passes at most the given number of errors on to the inner sink and drops the
+/// remaining ones. Used by derived validators for `#[validate(max_errors = N)]`.
+pub struct MaxErrorsSink<'a> {
+    inner: &'a mut dyn ErrorSink,
+    remaining: usize,
+}
 
-        if errors.is_empty() {
-            Ok(())
-        } else {
-            Err(errors)
+impl<'a> MaxErrorsSink<'a> {
+    pub fn new(inner: &'a mut dyn ErrorSink, max_errors: usize) -> Self {
+        Self {
+            inner,
+            remaining: max_errors,
         }
     }
+}
 
-    /// Similar to validate() except that errors are returned in the passed vector.
-    fn validate_ex(&self, path: &ValidationPath, errors: &mut Vec<ValidationError>) {
-        Self::DefaultValidator::default().validate(path, self, errors);
+impl ErrorSink for MaxErrorsSink<'_> {
+    fn push(&mut self, error: ValidationError) {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            self.inner.push(error);
+        }
     }
 }
 
-/// A validator that is never returning errors.
-#[derive(Default)]
-pub struct AlwaysValidValidator {}
+/// Error sink that changes the errors before passing them on to the inner sink.
+struct MapErrorSink<'a, F: Fn(&mut ValidationError)> {
+    inner: &'a mut dyn ErrorSink,
+    map: F,
+}
 
-impl<T> Validator<T> for AlwaysValidValidator {
-    fn validate(&self, _path: &ValidationPath, _value: &T, _errors: &mut Vec<ValidationError>) {}
+impl<F: Fn(&mut ValidationError)> ErrorSink for MapErrorSink<'_, F> {
+    fn push(&mut self, mut error: ValidationError) {
+        (self.map)(&mut error);
+        self.inner.push(error);
+    }
+}
+
+/// A validator for type T.
+pub trait Validator<T> {
+    /// Validate the passed value stored at the passed path. Errors are passed to the errors sink.
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink);
+
+    /// Validate the passed value stored at the passed path but stop at the first error which is
+    /// returned via Break. Validators of nested values override this to skip the remaining values.
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        let mut errors = Vec::new();
+        self.validate(path, value, &mut errors);
+
+        match errors.into_iter().next() {
+            Some(error) => ControlFlow::Break(error),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
+pub trait Validatable: Sized {
+    /// Default validator for values of this type.
+    type DefaultValidator: Validator<Self> + Default;
+
+    /// Validate this value using the default validator.
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut errors = Vec::new();
+        self.validate_visit(&mut |error| errors.push(error.clone()));
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Validate this value using the default validator and pass each error to the visitor as soon
+    /// as it is found. Allows custom aggregation like counting or capping errors without
+    /// collecting them first.
+    fn validate_visit(&self, mut visitor: &mut dyn FnMut(&ValidationError)) {
+        self.validate_ex(&ValidationPath::Root, &mut visitor);
+    }
+
+    /// Validate this instance with the given validator.
+    fn validate_with<V>(&self, validator: &V) -> Result<(), Vec<ValidationError>>
+    where
+        V: Validator<Self>,
+    {
+        let mut errors = Vec::new();
+        validator.validate(&ValidationPath::Root, self, &mut errors);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Like validate() but the errors are returned as [ValidationErrors] which implements
+    /// [std::error::Error].
+    fn validate_err(&self) -> Result<(), ValidationErrors> {
+        self.validate().map_err(ValidationErrors)
+    }
+
+    /// Like validate() but the errors are grouped by their path, see
+    /// [ValidationErrors::group_by_path].
+    fn validate_grouped(&self) -> Result<(), BTreeMap<String, Vec<ValidationError>>> {
+        self.validate_err().map_err(ValidationErrors::group_by_path)
+    }
+
+    /// Like validate() but errors are ordered breadth-first, i.e. errors of shallow paths come
+    /// before errors of nested paths. Errors of the same depth keep their order.
+    fn validate_breadth_first(&self) -> Result<(), Vec<ValidationError>> {
+        self.validate().map_err(|mut errors| {
+            errors.sort_by_key(ValidationError::depth);
+            errors
+        })
+    }
+
+    /// Validate this value using the default validator and return it if it is valid. Allows to
+    /// pass validated values on, e.g. `let value = value.validate_owned()?;`.
+    fn validate_owned(self) -> Result<Self, Vec<ValidationError>> {
+        self.validate().map(|()| self)
+    }
+
+    /// Validate this value using the default validator and return it together with the errors
+    /// of [Severity::Warning] if there are no other errors. Otherwise all errors are returned.
+    #[allow(clippy::type_complexity)]
+    fn validate_owned_with_warnings(
+        self,
+    ) -> Result<(Self, Vec<ValidationError>), Vec<ValidationError>> {
+        match self.validate() {
+            Ok(()) => Ok((self, Vec::new())),
+            Err(errors)
+                if errors
+                    .iter()
+                    .all(|error| error.severity == Severity::Warning) =>
+            {
+                Ok((self, errors))
+            }
+            Err(errors) => Err(errors),
+        }
+    }
+
+    /// Normalizes this value and validates it afterwards using the default validator.
+    fn normalize_and_validate(&mut self) -> Result<(), Vec<ValidationError>>
+    where
+        Self: Normalizable,
+    {
+        self.normalize();
+        self.validate()
+    }
+
+    /// Validate this value using the default validator but stop at the first error.
+    #[allow(clippy::result_large_err)]
+    fn validate_first(&self) -> Result<(), ValidationError> {
+        match Self::DefaultValidator::default().validate_first(&ValidationPath::Root, self) {
+            ControlFlow::Break(error) => Err(error),
+            ControlFlow::Continue(()) => Ok(()),
+        }
+    }
+
+    /// Returns whether this value is valid using the default validator. Stops at the first error.
+    fn is_valid(&self) -> bool {
+        self.validate_first().is_ok()
+    }
+
+    /// Similar to validate() except that errors are passed to the passed sink.
+    fn validate_ex(&self, path: &ValidationPath, errors: &mut dyn ErrorSink) {
+        Self::DefaultValidator::default().validate(path, self, errors);
+    }
+}
+
+/// Trait for values that can be validated against a previous version of themselves, e.g. for
+/// partial updates. Implemented by the derive macro for containers annotated with
+/// `#[validate(changed)]`. Only fields that differ from the previous value are validated, so the
+/// field types must implement PartialEq. Enums are validated as a whole if they changed.
+pub trait ValidateChanged: Validatable {
+    /// Validates the fields of this value that differ from the previous value.
+    fn validate_changed(&self, previous: &Self) -> Result<(), Vec<ValidationError>>;
+}
+
+/// Validates the passed value and panics with the validation errors if it is invalid. Like
+/// debug_assert!, the check is only performed if debug assertions are enabled.
+#[macro_export]
+macro_rules! debug_validate {
+    ($value:expr) => {
+        if cfg!(debug_assertions) {
+            if let Err(errors) = $crate::Validatable::validate(&$value) {
+                panic!(
+                    "Validation failed: {}",
+                    errors
+                        .iter()
+                        .map(|error| error.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+        }
+    };
+}
+
+/// Validates several values and merges their errors, e.g.
+/// `validate_all!("query" => &query, "body" => &body)`. The errors of each value are placed below
+/// the passed name like for fields.
+#[macro_export]
+macro_rules! validate_all {
+    ($($name:expr => $value:expr),+ $(,)?) => {{
+        let mut errors = ::std::vec::Vec::new();
+
+        $(
+            $crate::Validatable::validate_ex(
+                $value,
+                &$crate::ValidationPath::Field {
+                    parent: &$crate::ValidationPath::Root,
+                    name: $name,
+                },
+                &mut errors,
+            );
+        )+
+
+        if errors.is_empty() {
+            ::std::result::Result::Ok(())
+        } else {
+            ::std::result::Result::Err(errors)
+        }
+    }};
+}
+
+/// Description of the constraints of a field as returned by the `constraints()` method of derived
+/// validators. The constraints are pairs of schema keywords and their values.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FieldConstraint {
+    pub field: &'static str,
+    pub constraints: Vec<(&'static str, &'static str)>,
+}
+
+/// Validators that replace the validation of single fields. Used by derived validators, see their
+/// `with_<field>_override` methods which ensure that the validators match the field types.
+#[derive(Default)]
+pub struct FieldOverrides {
+    overrides: HashMap<&'static str, Box<dyn Any + Send + Sync>>,
+}
+
+impl FieldOverrides {
+    /// Replaces the validation of the field with the passed validator.
+    pub fn insert<T, V>(&mut self, field: &'static str, validator: V)
+    where
+        T: 'static,
+        V: Validator<T> + Send + Sync + 'static,
+    {
+        let validator: Box<dyn Validator<T> + Send + Sync> = Box::new(validator);
+
+        self.overrides.insert(field, Box::new(validator));
+    }
+
+    fn get<T: 'static>(&self, field: &str) -> Option<&(dyn Validator<T> + Send + Sync)> {
+        self.overrides
+            .get(field)?
+            .downcast_ref::<Box<dyn Validator<T> + Send + Sync>>()
+            .map(|validator| validator.as_ref())
+    }
+
+    /// Validates the value with the override of the field. Returns false if the field has no
+    /// override for values of type T and must be validated as usual.
+    pub fn validate<T: 'static>(
+        &self,
+        field: &str,
+        path: &ValidationPath,
+        value: &T,
+        errors: &mut dyn ErrorSink,
+    ) -> bool {
+        match self.get::<T>(field) {
+            Some(validator) => {
+                validator.validate(path, value, errors);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Like `validate` but stops at the first error which is returned via Break.
+    pub fn validate_first<T: 'static>(
+        &self,
+        field: &str,
+        path: &ValidationPath,
+        value: &T,
+    ) -> ControlFlow<ValidationError, bool> {
+        match self.get::<T>(field) {
+            Some(validator) => {
+                validator.validate_first(path, value)?;
+                ControlFlow::Continue(true)
+            }
+            None => ControlFlow::Continue(false),
+        }
+    }
+}
+
+/// Trait for values that can be normalized before they are validated. Implemented by the derive
+/// macro for the fields annotated with normalizers like `#[validate(trim, lowercase)]`.
+pub trait Normalizable {
+    /// Normalizes this value in place.
+    fn normalize(&mut self);
+}
+
+/// A normalizer for type T.
+pub trait Normalizer<T> {
+    /// Normalizes the passed value in place.
+    fn normalize(&self, value: &mut T);
+}
+
+/// Normalizer that removes leading and trailing whitespace.
+#[derive(Default)]
+pub struct TrimNormalizer {}
+
+impl Normalizer<String> for TrimNormalizer {
+    fn normalize(&self, value: &mut String) {
+        let trimmed = value.trim();
+
+        if trimmed.len() != value.len() {
+            *value = trimmed.to_owned();
+        }
+    }
+}
+
+/// Normalizer that converts strings to lowercase.
+#[derive(Default)]
+pub struct LowercaseNormalizer {}
+
+impl Normalizer<String> for LowercaseNormalizer {
+    fn normalize(&self, value: &mut String) {
+        *value = value.to_lowercase();
+    }
+}
+
+/// Implements [`Normalizer`] for Option values by normalizing the inner value if present.
+macro_rules! option_normalizer {
+    ($normalizer:ty => $type:ty) => {
+        impl Normalizer<Option<$type>> for $normalizer {
+            fn normalize(&self, value: &mut Option<$type>) {
+                if let Some(value) = value {
+                    Normalizer::<$type>::normalize(self, value);
+                }
+            }
+        }
+    };
+}
+
+option_normalizer!(TrimNormalizer => String);
+option_normalizer!(LowercaseNormalizer => String);
+
+/// A validator that sets a custom message on the errors of the inner validator, e.g. for
+/// `#[validate(message = "...")]`.
+pub struct MessageValidator<V> {
+    inner: V,
+    message: &'static str,
+}
+
+impl<V> MessageValidator<V> {
+    pub fn new(inner: V, message: &'static str) -> Self {
+        Self { inner, message }
+    }
+}
+
+impl<T, V> Validator<T> for MessageValidator<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let mut errors = MapErrorSink {
+            inner: errors,
+            map: |error: &mut ValidationError| error.custom_message = Some(self.message.to_owned()),
+        };
+
+        self.inner.validate(path, value, &mut errors);
+    }
+
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        match self.inner.validate_first(path, value) {
+            ControlFlow::Break(error) => ControlFlow::Break(ValidationError {
+                custom_message: Some(self.message.to_owned()),
+                ..error
+            }),
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// A validator that turns the errors of the inner validator into warnings, e.g. for
+/// `#[validate(warning)]`.
+pub struct WarningValidator<V> {
+    inner: V,
+}
+
+impl<V> WarningValidator<V> {
+    pub fn new(inner: V) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, V> Validator<T> for WarningValidator<V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let mut errors = MapErrorSink {
+            inner: errors,
+            map: |error: &mut ValidationError| error.severity = Severity::Warning,
+        };
+
+        self.inner.validate(path, value, &mut errors);
+    }
+
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        match self.inner.validate_first(path, value) {
+            ControlFlow::Break(error) => ControlFlow::Break(ValidationError {
+                severity: Severity::Warning,
+                ..error
+            }),
+            ControlFlow::Continue(()) => ControlFlow::Continue(()),
+        }
+    }
+}
+
+/// A validator that is never returning errors.
+#[derive(Default)]
+pub struct AlwaysValidValidator {}
+
+impl<T> Validator<T> for AlwaysValidValidator {
+    fn validate(&self, _path: &ValidationPath, _value: &T, _errors: &mut dyn ErrorSink) {}
+}
+
+/// A validator that calls a function to validate values, e.g. for validators given via
+/// `#[validate(with = path::to::function)]`. The function runs after the validation of nested
+/// values and in the order of the keywords relative to the schema checks of the field.
+pub struct FnValidator<T, F>
+where
+    F: Fn(&T, &ValidationPath, &mut dyn ErrorSink),
+{
+    function: F,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, F> FnValidator<T, F>
+where
+    F: Fn(&T, &ValidationPath, &mut dyn ErrorSink),
+{
+    pub fn new(function: F) -> Self {
+        Self {
+            function,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, F> Validator<T> for FnValidator<T, F>
+where
+    F: Fn(&T, &ValidationPath, &mut dyn ErrorSink),
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        (self.function)(value, path, errors);
+    }
 }
 
 macro_rules! validatable {
@@ -185,6 +1361,7 @@ macro_rules! validatable {
     };
 }
 
+validatable!(());
 validatable!(bool);
 validatable!(i8);
 validatable!(i16);
@@ -200,82 +1377,596 @@ validatable!(f32);
 validatable!(f64);
 validatable!(char);
 validatable!(String);
+validatable!(&str);
+validatable!(Cow<'_, str>);
+validatable!(Box<str>);
+validatable!(Arc<str>);
+#[cfg(feature = "time")]
+validatable!(time::OffsetDateTime);
+#[cfg(feature = "time")]
+validatable!(time::Date);
 
-/// A validator for Option. Implements the validator trait with a custom and the default validator
-/// for the inner type.
-pub struct OptionValidator<T, V>
+/// A date given as string literal bound like `#[validate(maximum = "2023-12-31")]`. The derive
+/// macro parses and checks the literal at compile time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BoundDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// A date time given as string literal bound like
+/// `#[validate(minimum = "2023-01-01T00:00:00Z")]`. The derive macro parses and checks the literal
+/// at compile time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct BoundDateTime {
+    pub date: BoundDate,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    /// Offset from UTC in seconds.
+    pub offset: i32,
+}
+
+/// Trait for types whose bounds in range checks can be given as string literals, e.g.
+/// `#[validate(minimum = "2023-01-01")]`. L is the parsed literal, i.e. [BoundDate] or
+/// [BoundDateTime].
+pub trait FromBoundLiteral<L> {
+    /// Creates the bound from the literal. Panics if the literal is out of range which is ruled
+    /// out for literals checked by the derive macro.
+    fn from_bound_literal(literal: L) -> Self;
+}
+
+#[cfg(feature = "time")]
+impl FromBoundLiteral<BoundDate> for time::Date {
+    fn from_bound_literal(literal: BoundDate) -> Self {
+        time::Month::try_from(literal.month)
+            .and_then(|month| time::Date::from_calendar_date(literal.year, month, literal.day))
+            .expect("Expected a valid date")
+    }
+}
+
+#[cfg(feature = "time")]
+impl FromBoundLiteral<BoundDateTime> for time::OffsetDateTime {
+    fn from_bound_literal(literal: BoundDateTime) -> Self {
+        time::Date::from_bound_literal(literal.date)
+            .with_hms_nano(
+                literal.hour,
+                literal.minute,
+                literal.second,
+                literal.nanosecond,
+            )
+            .and_then(|date_time| {
+                time::UtcOffset::from_whole_seconds(literal.offset)
+                    .map(|offset| date_time.assume_offset(offset))
+            })
+            .expect("Expected a valid date time")
+    }
+}
+
+/// Trait for wrapper types that are validated like their inner value. Usually implemented via the
+/// [impl_transparent_validatable] macro.
+pub trait Transparent {
+    /// Type of the wrapped value.
+    type Inner: Validatable;
+
+    /// Returns the wrapped value.
+    fn inner(&self) -> &Self::Inner;
+}
+
+/// A validator for transparent wrapper types that validates the wrapped value. Implements the
+/// validator trait with a custom and the default validator for the inner type.
+pub struct TransparentValidator<T, V>
 where
-    T: Validatable,
-    V: Validator<T>,
+    T: Transparent,
+    V: Validator<T::Inner>,
 {
     inner: V,
     phantom: PhantomData<T>,
 }
 
-impl<T, V> OptionValidator<T, V>
+impl<T, V> TransparentValidator<T, V>
 where
-    T: Validatable,
-    V: Validator<T>,
+    T: Transparent,
+    V: Validator<T::Inner>,
 {
     pub fn new(inner: V) -> Self {
         Self {
             inner,
-            phantom: PhantomData::default(),
+            phantom: PhantomData,
         }
     }
 }
 
-impl<T: Validatable> Default for OptionValidator<T, T::DefaultValidator> {
+impl<T> Default for TransparentValidator<T, <T::Inner as Validatable>::DefaultValidator>
+where
+    T: Transparent,
+{
     fn default() -> Self {
         Self {
-            inner: T::DefaultValidator::default(),
-            phantom: PhantomData::default(),
+            inner: <T::Inner as Validatable>::DefaultValidator::default(),
+            phantom: PhantomData,
         }
     }
 }
 
-impl<T, V> Validator<Option<T>> for OptionValidator<T, V>
+impl<T, V> Validator<T> for TransparentValidator<T, V>
 where
-    T: Validatable,
-    V: Validator<T>,
+    T: Transparent,
+    V: Validator<T::Inner>,
 {
-    fn validate(
-        &self,
-        path: &ValidationPath,
-        value: &Option<T>,
-        errors: &mut Vec<ValidationError>,
-    ) {
-        if let Some(value) = value {
-            self.inner.validate(path, value, errors);
-        }
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        self.inner.validate(path, value.inner(), errors);
     }
-}
 
-impl<T> Validatable for Option<T>
-where
-    T: Validatable,
-{
-    type DefaultValidator = OptionValidator<T, T::DefaultValidator>;
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        self.inner.validate_first(path, value.inner())
+    }
 }
 
-/// A validator for vectors that iterates over the items. Implements the validator trait with a
-/// custom and the default validator for the item type.
-pub struct VecValidator<T, V>
-where
-    T: Validatable,
-    V: Validator<T>,
-{
-    inner: V,
-    phantom: PhantomData<T>,
+macro_rules! transparent_pointer {
+    ($pointer:ident) => {
+        impl<T: Validatable> Transparent for $pointer<T> {
+            type Inner = T;
+
+            fn inner(&self) -> &Self::Inner {
+                self
+            }
+        }
+
+        impl<T: Validatable> Validatable for $pointer<T> {
+            type DefaultValidator = TransparentValidator<Self, T::DefaultValidator>;
+        }
+    };
 }
 
-impl<T: Validatable> Default for VecValidator<T, T::DefaultValidator> {
-    fn default() -> Self {
-        Self {
-            inner: T::DefaultValidator::default(),
-            phantom: PhantomData::default(),
+transparent_pointer!(Box);
+transparent_pointer!(Rc);
+transparent_pointer!(Arc);
+
+macro_rules! transparent_number {
+    ($wrapper:ident) => {
+        impl<T: Validatable> Transparent for std::num::$wrapper<T> {
+            type Inner = T;
+
+            fn inner(&self) -> &Self::Inner {
+                &self.0
+            }
         }
-    }
+
+        impl<T: Validatable> Validatable for std::num::$wrapper<T> {
+            type DefaultValidator = TransparentValidator<Self, T::DefaultValidator>;
+        }
+    };
+}
+
+transparent_number!(Wrapping);
+transparent_number!(Saturating);
+
+/// Implements [Validatable] for a newtype wrapper so that it is validated like the wrapped value,
+/// e.g. `impl_transparent_validatable!(Name => String)`.
+#[macro_export]
+macro_rules! impl_transparent_validatable {
+    ($type:ty => $inner:ty) => {
+        impl $crate::Transparent for $type {
+            type Inner = $inner;
+
+            fn inner(&self) -> &Self::Inner {
+                &self.0
+            }
+        }
+
+        impl $crate::Validatable for $type {
+            type DefaultValidator = $crate::TransparentValidator<
+                $type,
+                <$inner as $crate::Validatable>::DefaultValidator,
+            >;
+        }
+    };
+}
+
+/// A validator for tuples that validates each element with its default validator. The position of
+/// an element is used as item index in the path.
+pub struct TupleValidator<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> Default for TupleValidator<T> {
+    fn default() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+macro_rules! validatable_tuple {
+    ($($index:tt $type:ident),+) => {
+        impl<$($type: Validatable),+> Validator<($($type,)+)> for TupleValidator<($($type,)+)> {
+            fn validate(
+                &self,
+                path: &ValidationPath,
+                value: &($($type,)+),
+                errors: &mut dyn ErrorSink,
+            ) {
+                $(
+                    let item_path = ValidationPath::Item {
+                        parent: path,
+                        index: $index,
+                    };
+
+                    value.$index.validate_ex(&item_path, errors);
+                )+
+            }
+
+            fn validate_first(
+                &self,
+                path: &ValidationPath,
+                value: &($($type,)+),
+            ) -> ControlFlow<ValidationError> {
+                $(
+                    let item_path = ValidationPath::Item {
+                        parent: path,
+                        index: $index,
+                    };
+
+                    $type::DefaultValidator::default().validate_first(&item_path, &value.$index)?;
+                )+
+
+                ControlFlow::Continue(())
+            }
+        }
+
+        impl<$($type: Validatable),+> Validatable for ($($type,)+) {
+            type DefaultValidator = TupleValidator<($($type,)+)>;
+        }
+    };
+}
+
+validatable_tuple!(0 A);
+validatable_tuple!(0 A, 1 B);
+validatable_tuple!(0 A, 1 B, 2 C);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K);
+validatable_tuple!(0 A, 1 B, 2 C, 3 D, 4 E, 5 F, 6 G, 7 H, 8 I, 9 J, 10 K, 11 L);
+
+/// A validator for Option. Implements the validator trait with a custom and the default validator
+/// for the inner type.
+pub struct OptionValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> OptionValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable> Default for OptionValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<Option<T>> for OptionValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &Option<T>, errors: &mut dyn ErrorSink) {
+        if let Some(value) = value {
+            self.inner.validate(path, value, errors);
+        }
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &Option<T>,
+    ) -> ControlFlow<ValidationError> {
+        match value {
+            Some(value) => self.inner.validate_first(path, value),
+            None => ControlFlow::Continue(()),
+        }
+    }
+}
+
+impl<T> Validatable for Option<T>
+where
+    T: Validatable,
+{
+    type DefaultValidator = OptionValidator<T, T::DefaultValidator>;
+}
+
+/// A field of a PATCH request which distinguishes whether the value is absent, explicitly null or
+/// present. This replaces the `Option<Option<T>>` pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Patch<T> {
+    /// The field is not part of the request and should be left unchanged.
+    #[default]
+    Absent,
+    /// The field is explicitly null and should be cleared.
+    Null,
+    /// The field should be set to the value.
+    Present(T),
+}
+
+impl<T> Patch<T> {
+    /// Returns whether the field is not part of the request.
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    /// Returns whether the field is explicitly null.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    /// Returns whether the field has a value.
+    pub fn is_present(&self) -> bool {
+        matches!(self, Patch::Present(_))
+    }
+
+    /// Returns the value if present.
+    pub fn as_present(&self) -> Option<&T> {
+        match self {
+            Patch::Present(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Converts into the double option representation, i.e. None for absent and Some(None) for
+    /// null values.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Present(value) => Some(Some(value)),
+        }
+    }
+}
+
+impl<T> From<Option<Option<T>>> for Patch<T> {
+    fn from(value: Option<Option<T>>) -> Self {
+        match value {
+            None => Patch::Absent,
+            Some(None) => Patch::Null,
+            Some(Some(value)) => Patch::Present(value),
+        }
+    }
+}
+
+/// A validator for [Patch] that only validates present values. Implements the validator trait with
+/// a custom and the default validator for the inner type.
+pub struct PatchValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> PatchValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable> Default for PatchValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<Patch<T>> for PatchValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &Patch<T>, errors: &mut dyn ErrorSink) {
+        if let Patch::Present(value) = value {
+            self.inner.validate(path, value, errors);
+        }
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &Patch<T>,
+    ) -> ControlFlow<ValidationError> {
+        match value {
+            Patch::Present(value) => self.inner.validate_first(path, value),
+            _ => ControlFlow::Continue(()),
+        }
+    }
+}
+
+impl<T> Validatable for Patch<T>
+where
+    T: Validatable,
+{
+    type DefaultValidator = PatchValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for RefCell that borrows and validates the inner value. If the value is mutably
+/// borrowed, an [ValidationErrorCategory::Other] error tagged "borrowed" is reported. Implements the
+/// validator trait with a custom and the default validator for the inner type.
+pub struct RefCellValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> RefCellValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable> Default for RefCellValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<RefCell<T>> for RefCellValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &RefCell<T>, errors: &mut dyn ErrorSink) {
+        match value.try_borrow() {
+            Ok(value) => self.inner.validate(path, &value, errors),
+            Err(_) => errors.push(ValidationError {
+                category: ValidationErrorCategory::Other {
+                    tag: "borrowed",
+                    display: |error, f| write!(f, "{}: Must not be mutably borrowed", error.path),
+                    detail: None,
+                },
+                path: path.to_string(),
+                index: path.index(),
+                actual: "mutably borrowed".to_owned(),
+                expected: "not mutably borrowed".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            }),
+        }
+    }
+}
+
+impl<T> Validatable for RefCell<T>
+where
+    T: Validatable,
+{
+    type DefaultValidator = RefCellValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for Cell that validates a copy of the inner value. Implements the validator trait
+/// with a custom and the default validator for the inner type.
+pub struct CellValidator<T, V>
+where
+    T: Validatable + Copy,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> CellValidator<T, V>
+where
+    T: Validatable + Copy,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable + Copy> Default for CellValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<Cell<T>> for CellValidator<T, V>
+where
+    T: Validatable + Copy,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &Cell<T>, errors: &mut dyn ErrorSink) {
+        self.inner.validate(path, &value.get(), errors);
+    }
+}
+
+impl<T> Validatable for Cell<T>
+where
+    T: Validatable + Copy,
+{
+    type DefaultValidator = CellValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for vectors that iterates over the items. Implements the validator trait with a
+/// custom and the default validator for the item type.
+pub struct VecValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> VecValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable> Default for VecValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
 }
 
 impl<T, V> Validator<Vec<T>> for VecValidator<T, V>
@@ -283,306 +1974,2513 @@ where
     T: Validatable,
     V: Validator<T>,
 {
-    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut Vec<ValidationError>) {
+    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut dyn ErrorSink) {
         for (index, item) in value.iter().enumerate() {
             let item_path = ValidationPath::Item {
                 parent: path,
                 index,
             };
 
-            self.inner.validate(&item_path, item, errors);
-        }
-    }
+            self.inner.validate(&item_path, item, errors);
+        }
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &Vec<T>,
+    ) -> ControlFlow<ValidationError> {
+        for (index, item) in value.iter().enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            self.inner.validate_first(&item_path, item)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T> Validatable for Vec<T>
+where
+    T: Validatable,
+{
+    type DefaultValidator = VecValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for arrays that iterates over the items. Implements the validator trait with a
+/// custom and the default validator for the item type.
+pub struct ArrayValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> ArrayValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Validatable> Default for ArrayValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V, const N: usize> Validator<[T; N]> for ArrayValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &[T; N], errors: &mut dyn ErrorSink) {
+        for (index, item) in value.iter().enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            self.inner.validate(&item_path, item, errors);
+        }
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &[T; N],
+    ) -> ControlFlow<ValidationError> {
+        for (index, item) in value.iter().enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            self.inner.validate_first(&item_path, item)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T, const N: usize> Validatable for [T; N]
+where
+    T: Validatable,
+{
+    type DefaultValidator = ArrayValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for vectors of a fixed shape that validates the i-th item with the i-th validator.
+/// Differing lengths are reported as [ValidationErrorCategory::MaxItems] or
+/// [ValidationErrorCategory::MinItems] errors. Validators of different types can be combined as
+/// `Box<dyn Validator<T>>`.
+pub struct PositionalValidator<T, V>
+where
+    V: Validator<T>,
+{
+    validators: Vec<V>,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, V> PositionalValidator<T, V>
+where
+    V: Validator<T>,
+{
+    pub fn new(validators: Vec<V>) -> Self {
+        Self {
+            validators,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<Vec<T>> for PositionalValidator<T, V>
+where
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut dyn ErrorSink) {
+        let category = match value.len().cmp(&self.validators.len()) {
+            Ordering::Greater => Some(ValidationErrorCategory::MaxItems),
+            Ordering::Less => Some(ValidationErrorCategory::MinItems),
+            Ordering::Equal => None,
+        };
+
+        if let Some(category) = category {
+            errors.push(ValidationError {
+                category,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.len().to_string(),
+                expected: self.validators.len().to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+
+        for (index, (item, validator)) in value.iter().zip(&self.validators).enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            validator.validate(&item_path, item, errors);
+        }
+    }
+}
+
+impl<T, V> Validator<T> for Box<V>
+where
+    V: Validator<T> + ?Sized,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        (**self).validate(path, value, errors);
+    }
+
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        (**self).validate_first(path, value)
+    }
+}
+
+/// A validator that runs several validators on the same value in order and collects all of their
+/// errors, e.g. `ChainValidator::new(vec![Box::new(MinimumValidator::new(0)), ...])`.
+pub struct ChainValidator<T> {
+    validators: Vec<Box<dyn Validator<T>>>,
+}
+
+impl<T> ChainValidator<T> {
+    pub fn new(validators: Vec<Box<dyn Validator<T>>>) -> Self {
+        Self { validators }
+    }
+}
+
+impl<T> Default for ChainValidator<T> {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl<T> Validator<T> for ChainValidator<T> {
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        for validator in &self.validators {
+            validator.validate(path, value, errors);
+        }
+    }
+
+    fn validate_first(&self, path: &ValidationPath, value: &T) -> ControlFlow<ValidationError> {
+        for validator in &self.validators {
+            validator.validate_first(path, value)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+/// A validator for sets that iterates over the items in the iteration order of the set.
+/// Implements the validator trait with a custom and the default validator for the item type.
+pub struct SetValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> SetValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    fn validate_items<'a>(
+        &self,
+        path: &ValidationPath,
+        items: impl Iterator<Item = &'a T>,
+        errors: &mut dyn ErrorSink,
+    ) where
+        T: 'a,
+    {
+        for (index, item) in items.enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            self.inner.validate(&item_path, item, errors);
+        }
+    }
+
+    fn validate_first_item<'a>(
+        &self,
+        path: &ValidationPath,
+        items: impl Iterator<Item = &'a T>,
+    ) -> ControlFlow<ValidationError>
+    where
+        T: 'a,
+    {
+        for (index, item) in items.enumerate() {
+            let item_path = ValidationPath::Item {
+                parent: path,
+                index,
+            };
+
+            self.inner.validate_first(&item_path, item)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<T: Validatable> Default for SetValidator<T, T::DefaultValidator> {
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V, S> Validator<HashSet<T, S>> for SetValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &HashSet<T, S>, errors: &mut dyn ErrorSink) {
+        self.validate_items(path, value.iter(), errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &HashSet<T, S>,
+    ) -> ControlFlow<ValidationError> {
+        self.validate_first_item(path, value.iter())
+    }
+}
+
+impl<T, V> Validator<BTreeSet<T>> for SetValidator<T, V>
+where
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &BTreeSet<T>, errors: &mut dyn ErrorSink) {
+        self.validate_items(path, value.iter(), errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &BTreeSet<T>,
+    ) -> ControlFlow<ValidationError> {
+        self.validate_first_item(path, value.iter())
+    }
+}
+
+impl<T, S> Validatable for HashSet<T, S>
+where
+    T: Validatable,
+{
+    type DefaultValidator = SetValidator<T, T::DefaultValidator>;
+}
+
+impl<T> Validatable for BTreeSet<T>
+where
+    T: Validatable,
+{
+    type DefaultValidator = SetValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for maps that iterates over the entries and validates the values. The key of an
+/// entry is rendered as `["key"]` in the path. Implements the validator trait with a custom and the
+/// default validator for the value type.
+pub struct MapValidator<K, T, V>
+where
+    K: Display,
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<(K, T)>,
+}
+
+impl<K, T, V> MapValidator<K, T, V>
+where
+    K: Display,
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Returns a validator that validates the entries ordered by key, e.g. to get deterministic
+    /// errors for HashMap.
+    pub fn sorted(self) -> SortedMapValidator<K, T, V>
+    where
+        K: Ord,
+    {
+        SortedMapValidator { inner: self }
+    }
+
+    fn validate_entries<'a>(
+        &self,
+        path: &ValidationPath,
+        entries: impl Iterator<Item = (&'a K, &'a T)>,
+        errors: &mut dyn ErrorSink,
+    ) where
+        K: 'a,
+        T: 'a,
+    {
+        for (key, value) in entries {
+            let key = key.to_string();
+            let value_path = ValidationPath::Key {
+                parent: path,
+                key: &key,
+            };
+
+            self.inner.validate(&value_path, value, errors);
+        }
+    }
+
+    fn validate_first_entry<'a>(
+        &self,
+        path: &ValidationPath,
+        entries: impl Iterator<Item = (&'a K, &'a T)>,
+    ) -> ControlFlow<ValidationError>
+    where
+        K: 'a,
+        T: 'a,
+    {
+        for (key, value) in entries {
+            let key = key.to_string();
+            let value_path = ValidationPath::Key {
+                parent: path,
+                key: &key,
+            };
+
+            self.inner.validate_first(&value_path, value)?;
+        }
+
+        ControlFlow::Continue(())
+    }
+}
+
+impl<K, T> Default for MapValidator<K, T, T::DefaultValidator>
+where
+    K: Display,
+    T: Validatable,
+{
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K, T, V, S> Validator<HashMap<K, T, S>> for MapValidator<K, T, V>
+where
+    K: Display,
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_entries(path, value.iter(), errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+    ) -> ControlFlow<ValidationError> {
+        self.validate_first_entry(path, value.iter())
+    }
+}
+
+impl<K, T, V> Validator<BTreeMap<K, T>> for MapValidator<K, T, V>
+where
+    K: Display,
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.validate_entries(path, value.iter(), errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &BTreeMap<K, T>,
+    ) -> ControlFlow<ValidationError> {
+        self.validate_first_entry(path, value.iter())
+    }
+}
+
+impl<K, T, S> Validatable for HashMap<K, T, S>
+where
+    K: Display,
+    T: Validatable,
+{
+    type DefaultValidator = MapValidator<K, T, T::DefaultValidator>;
+}
+
+impl<K, T> Validatable for BTreeMap<K, T>
+where
+    K: Display,
+    T: Validatable,
+{
+    type DefaultValidator = MapValidator<K, T, T::DefaultValidator>;
+}
+
+/// A validator for maps that validates the entries ordered by key like for BTreeMap. Created via
+/// [MapValidator::sorted] or `#[validate(sorted)]`.
+pub struct SortedMapValidator<K, T, V>
+where
+    K: Display + Ord,
+    T: Validatable,
+    V: Validator<T>,
+{
+    inner: MapValidator<K, T, V>,
+}
+
+impl<K, T, V> SortedMapValidator<K, T, V>
+where
+    K: Display + Ord,
+    T: Validatable,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        MapValidator::new(inner).sorted()
+    }
+}
+
+impl<K, T> Default for SortedMapValidator<K, T, T::DefaultValidator>
+where
+    K: Display + Ord,
+    T: Validatable,
+{
+    fn default() -> Self {
+        MapValidator::default().sorted()
+    }
+}
+
+impl<K, T, V, S> Validator<HashMap<K, T, S>> for SortedMapValidator<K, T, V>
+where
+    K: Display + Ord,
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        let mut entries = value.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(key, _)| *key);
+
+        self.inner
+            .validate_entries(path, entries.into_iter(), errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+    ) -> ControlFlow<ValidationError> {
+        let mut entries = value.iter().collect::<Vec<_>>();
+        entries.sort_by_key(|(key, _)| *key);
+
+        self.inner.validate_first_entry(path, entries.into_iter())
+    }
+}
+
+impl<K, T, V> Validator<BTreeMap<K, T>> for SortedMapValidator<K, T, V>
+where
+    K: Display + Ord,
+    T: Validatable,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.inner.validate(path, value, errors);
+    }
+
+    fn validate_first(
+        &self,
+        path: &ValidationPath,
+        value: &BTreeMap<K, T>,
+    ) -> ControlFlow<ValidationError> {
+        self.inner.validate_first(path, value)
+    }
+}
+
+/// A validator for ranges that validates the start and end and reports an error if the start is
+/// greater than the end. Implements the validator trait with a custom and the default validator
+/// for the bound type.
+pub struct RangeValidator<T, V>
+where
+    T: Validatable + PartialOrd + Display,
+    V: Validator<T>,
+{
+    inner: V,
+    phantom: PhantomData<T>,
+}
+
+impl<T, V> RangeValidator<T, V>
+where
+    T: Validatable + PartialOrd + Display,
+    V: Validator<T>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    fn validate_bounds(
+        &self,
+        path: &ValidationPath,
+        start: &T,
+        end: &T,
+        errors: &mut dyn ErrorSink,
+    ) {
+        let start_path = ValidationPath::Field {
+            parent: path,
+            name: "start",
+        };
+        self.inner.validate(&start_path, start, errors);
+
+        let end_path = ValidationPath::Field {
+            parent: path,
+            name: "end",
+        };
+        self.inner.validate(&end_path, end, errors);
+
+        if start > end {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::RangeOrder,
+                path: path.to_string(),
+                index: path.index(),
+                actual: start.to_string(),
+                expected: end.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+impl<T> Default for RangeValidator<T, T::DefaultValidator>
+where
+    T: Validatable + PartialOrd + Display,
+{
+    fn default() -> Self {
+        Self {
+            inner: T::DefaultValidator::default(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<Range<T>> for RangeValidator<T, V>
+where
+    T: Validatable + PartialOrd + Display,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &Range<T>, errors: &mut dyn ErrorSink) {
+        self.validate_bounds(path, &value.start, &value.end, errors);
+    }
+}
+
+impl<T, V> Validator<RangeInclusive<T>> for RangeValidator<T, V>
+where
+    T: Validatable + PartialOrd + Display,
+    V: Validator<T>,
+{
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &RangeInclusive<T>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_bounds(path, value.start(), value.end(), errors);
+    }
+}
+
+impl<T> Validatable for Range<T>
+where
+    T: Validatable + PartialOrd + Display,
+{
+    type DefaultValidator = RangeValidator<T, T::DefaultValidator>;
+}
+
+impl<T> Validatable for RangeInclusive<T>
+where
+    T: Validatable + PartialOrd + Display,
+{
+    type DefaultValidator = RangeValidator<T, T::DefaultValidator>;
+}
+
+/// A validator for maps that validates the keys. The path of a key is the
+/// [ValidationPath::Key] of its entry.
+pub struct MapKeyValidator<K, T, V>
+where
+    V: Validator<K>,
+{
+    inner: V,
+    phantom: PhantomData<(K, T)>,
+}
+
+impl<K, T, V> MapKeyValidator<K, T, V>
+where
+    K: Display,
+    V: Validator<K>,
+{
+    pub fn new(inner: V) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    fn validate_keys<'a>(
+        &self,
+        path: &ValidationPath,
+        keys: impl Iterator<Item = &'a K>,
+        errors: &mut dyn ErrorSink,
+    ) where
+        K: 'a,
+    {
+        for key in keys {
+            let key_str = key.to_string();
+            let key_path = ValidationPath::Key {
+                parent: path,
+                key: &key_str,
+            };
+
+            self.inner.validate(&key_path, key, errors);
+        }
+    }
+}
+
+impl<K, T, V, S> Validator<HashMap<K, T, S>> for MapKeyValidator<K, T, V>
+where
+    K: Display,
+    V: Validator<K>,
+{
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_keys(path, value.keys(), errors);
+    }
+}
+
+impl<K, T, V> Validator<BTreeMap<K, T>> for MapKeyValidator<K, T, V>
+where
+    K: Display,
+    V: Validator<K>,
+{
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.validate_keys(path, value.keys(), errors);
+    }
+}
+
+/// Validator for the 'exclusive_maximum' schema check.
+pub struct ExclusiveMaximumValidator<T: PartialOrd + Display> {
+    exclusive_maximum: T,
+}
+
+impl<T> ExclusiveMaximumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    pub fn new(exclusive_maximum: T) -> Self {
+        Self { exclusive_maximum }
+    }
+}
+
+impl<T> Validator<T> for ExclusiveMaximumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if *value >= self.exclusive_maximum {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::ExclusiveMaximum,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: self.exclusive_maximum.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'exclusive_minimum' schema check.
+pub struct ExclusiveMinimumValidator<T: PartialOrd + Display> {
+    exclusive_minimum: T,
+}
+
+impl<T> ExclusiveMinimumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    pub fn new(exclusive_minimum: T) -> Self {
+        Self { exclusive_minimum }
+    }
+}
+
+impl<T> Validator<T> for ExclusiveMinimumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if *value <= self.exclusive_minimum {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::ExclusiveMinimum,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: self.exclusive_minimum.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'maximum' schema check. Supports all ordered types, the bound can be any
+/// expression of the field type like `#[validate(maximum = Version(2, 0))]`.
+pub struct MaximumValidator<T: PartialOrd + Display> {
+    maximum: T,
+}
+
+impl<T> MaximumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    pub fn new(maximum: T) -> Self {
+        Self { maximum }
+    }
+}
+
+impl<T> Validator<T> for MaximumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if *value > self.maximum {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Maximum,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: self.maximum.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'minimum' schema check. Supports all ordered types, the bound can be any
+/// expression of the field type like `#[validate(minimum = Version(1, 2))]`.
+pub struct MinimumValidator<T: PartialOrd + Display> {
+    minimum: T,
+}
+
+impl<T> MinimumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    pub fn new(minimum: T) -> Self {
+        Self { minimum }
+    }
+}
+
+impl<T> Validator<T> for MinimumValidator<T>
+where
+    T: PartialOrd + Display,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if *value < self.minimum {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Minimum,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: self.minimum.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for 'minimum' and 'maximum' bounds that compares via a custom comparator instead of
+/// PartialOrd, e.g. for case-insensitive string ordering. Both bounds are inclusive and optional.
+pub struct OrderedValidator<T, F>
+where
+    T: Display,
+    F: Fn(&T, &T) -> Ordering,
+{
+    minimum: Option<T>,
+    maximum: Option<T>,
+    comparator: F,
+}
+
+impl<T, F> OrderedValidator<T, F>
+where
+    T: Display,
+    F: Fn(&T, &T) -> Ordering,
+{
+    pub fn new(minimum: Option<T>, maximum: Option<T>, comparator: F) -> Self {
+        Self {
+            minimum,
+            maximum,
+            comparator,
+        }
+    }
+}
+
+impl<T, F> Validator<T> for OrderedValidator<T, F>
+where
+    T: Display,
+    F: Fn(&T, &T) -> Ordering,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let bounds = [
+            (
+                &self.minimum,
+                Ordering::Less,
+                ValidationErrorCategory::Minimum,
+            ),
+            (
+                &self.maximum,
+                Ordering::Greater,
+                ValidationErrorCategory::Maximum,
+            ),
+        ];
+
+        for (bound, violation, category) in bounds {
+            if let Some(bound) = bound {
+                if (self.comparator)(value, bound) == violation {
+                    errors.push(ValidationError {
+                        category,
+                        path: path.to_string(),
+                        index: path.index(),
+                        actual: value.to_string(),
+                        expected: bound.to_string(),
+                        custom_message: None,
+                        severity: Severity::Error,
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Validator for the 'max_length' schema check. The length is the number of Unicode characters.
+pub struct MaxLengthValidator {
+    max_length: usize,
+}
+
+impl MaxLengthValidator {
+    pub fn new(max_length: usize) -> Self {
+        Self { max_length }
+    }
+}
+
+impl<T> Validator<T> for MaxLengthValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let length = value.as_ref().chars().count();
+
+        if length > self.max_length {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MaxLength,
+                path: path.to_string(),
+                index: path.index(),
+                actual: length.to_string(),
+                expected: self.max_length.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'min_length' schema check. The length is the number of Unicode characters.
+pub struct MinLengthValidator {
+    min_length: usize,
+}
+
+impl MinLengthValidator {
+    pub fn new(min_length: usize) -> Self {
+        Self { min_length }
+    }
+}
+
+impl<T> Validator<T> for MinLengthValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let length = value.as_ref().chars().count();
+
+        if length < self.min_length {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MinLength,
+                path: path.to_string(),
+                index: path.index(),
+                actual: length.to_string(),
+                expected: self.min_length.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the maximum number of bytes of a string or byte vector, see
+/// `#[validate(byte_length(...))]` and `#[validate(length = "bytes")]`.
+pub struct MaxByteLengthValidator {
+    max_length: usize,
+}
+
+impl MaxByteLengthValidator {
+    pub fn new(max_length: usize) -> Self {
+        Self { max_length }
+    }
+}
+
+impl<T> Validator<T> for MaxByteLengthValidator
+where
+    T: AsRef<[u8]>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let length = value.as_ref().len();
+
+        if length > self.max_length {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MaxByteLength,
+                path: path.to_string(),
+                index: path.index(),
+                actual: length.to_string(),
+                expected: self.max_length.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the minimum number of bytes of a string or byte vector, see
+/// `#[validate(byte_length(...))]` and `#[validate(length = "bytes")]`.
+pub struct MinByteLengthValidator {
+    min_length: usize,
+}
+
+impl MinByteLengthValidator {
+    pub fn new(min_length: usize) -> Self {
+        Self { min_length }
+    }
+}
+
+impl<T> Validator<T> for MinByteLengthValidator
+where
+    T: AsRef<[u8]>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let length = value.as_ref().len();
+
+        if length < self.min_length {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MinByteLength,
+                path: path.to_string(),
+                index: path.index(),
+                actual: length.to_string(),
+                expected: self.min_length.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'pattern' schema check. The regex is either owned or borrowed, e.g. from a
+/// static that compiles it only once.
+#[cfg(feature = "regex")]
+pub struct PatternValidator<R: Borrow<Regex> = Regex> {
+    pattern: R,
+    max_input_len: Option<usize>,
+}
+
+#[cfg(feature = "regex")]
+impl<R: Borrow<Regex>> PatternValidator<R> {
+    pub fn new(pattern: R) -> Self {
+        Self {
+            pattern,
+            max_input_len: None,
+        }
+    }
+
+    /// Returns this validator rejecting values longer than the passed number of bytes without
+    /// running the regex. Such values are reported with an [ValidationErrorCategory::Other] error
+    /// tagged "pattern_input_too_long" whose actual value is the length of the value.
+    pub fn with_max_input_len(self, max_input_len: usize) -> Self {
+        Self {
+            max_input_len: Some(max_input_len),
+            ..self
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<R, T> Validator<T> for PatternValidator<R>
+where
+    R: Borrow<Regex>,
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let pattern = self.pattern.borrow();
+        let value = value.as_ref();
+
+        if let Some(max_input_len) = self.max_input_len.filter(|max| value.len() > *max) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Other {
+                    tag: "pattern_input_too_long",
+                    display: |error, f| {
+                        write!(
+                            f,
+                            "{}: Must have at most {} bytes to be matched but has {}",
+                            error.path, error.expected, error.actual
+                        )
+                    },
+                    detail: None,
+                },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.len().to_string(),
+                expected: max_input_len.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        } else if !pattern.is_match(value) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Pattern,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_owned(),
+                expected: pattern.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for patterns that are assembled at runtime. In contrast to [PatternValidator] an
+/// invalid pattern does not panic but is reported with an [ValidationErrorCategory::Other] error
+/// tagged "invalid_pattern".
+#[cfg(feature = "regex")]
+pub struct FalliblePatternValidator {
+    pattern: Result<Regex, String>,
+}
+
+#[cfg(feature = "regex")]
+impl FalliblePatternValidator {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: Regex::new(pattern).map_err(|_| pattern.to_owned()),
+        }
+    }
+}
+
+#[cfg(feature = "regex")]
+impl<T> Validator<T> for FalliblePatternValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        match &self.pattern {
+            Ok(pattern) => PatternValidator::new(pattern).validate(path, &value, errors),
+            Err(pattern) => errors.push(ValidationError {
+                category: ValidationErrorCategory::Other {
+                    tag: "invalid_pattern",
+                    display: |error, f| {
+                        write!(
+                            f,
+                            "{}: The pattern {} is invalid",
+                            error.path, error.expected
+                        )
+                    },
+                    detail: None,
+                },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: pattern.clone(),
+                custom_message: None,
+                severity: Severity::Error,
+            }),
+        }
+    }
+}
+
+/// Validator for URLs of the form `scheme://authority[path][?query][#fragment]`. If schemes are
+/// configured, the scheme of the URL must be one of them (compared case-insensitively).
+pub struct UrlValidator {
+    schemes: &'static [&'static str],
+}
+
+impl UrlValidator {
+    /// Creates a validator that restricts URLs to the passed schemes. An empty slice allows all
+    /// schemes.
+    pub fn new(schemes: &'static [&'static str]) -> Self {
+        Self { schemes }
+    }
+
+    fn parse_scheme(value: &str) -> Option<&str> {
+        let (scheme, rest) = value.split_once("://")?;
+        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+
+        let mut chars = scheme.chars();
+        let valid_scheme = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        let valid_rest =
+            !authority.is_empty() && !rest.chars().any(|c| c.is_whitespace() || c.is_control());
+
+        if valid_scheme && valid_rest {
+            Some(scheme)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Validator<T> for UrlValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        match Self::parse_scheme(value) {
+            None => errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "url" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "url".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            }),
+            Some(scheme)
+                if !self.schemes.is_empty()
+                    && !self
+                        .schemes
+                        .iter()
+                        .any(|allowed| allowed.eq_ignore_ascii_case(scheme)) =>
+            {
+                errors.push(ValidationError {
+                    category: ValidationErrorCategory::UrlScheme,
+                    path: path.to_string(),
+                    index: path.index(),
+                    actual: scheme.to_owned(),
+                    expected: self.schemes.join(", "),
+                    custom_message: None,
+                    severity: Severity::Error,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+/// Validator for `#[validate(required)]` that rejects missing optional values.
+pub struct RequiredValidator<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> RequiredValidator<T> {
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for RequiredValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Validator<Option<T>> for RequiredValidator<T> {
+    fn validate(&self, path: &ValidationPath, value: &Option<T>, errors: &mut dyn ErrorSink) {
+        if value.is_none() {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Required,
+                path: path.to_string(),
+                index: path.index(),
+                actual: "null".to_owned(),
+                expected: "a value".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'not' schema check that inverts the inner validator: values rejected by the
+/// inner validator are valid, values accepted by it are reported as
+/// [ValidationErrorCategory::Not] errors. The expected value of errors is the passed description,
+/// e.g. "a number" for `NotValidator::new(PatternValidator::new(..), "a number")`.
+pub struct NotValidator<T, V>
+where
+    V: Validator<T>,
+{
+    inner: V,
+    description: &'static str,
+    phantom: PhantomData<fn(&T)>,
+}
+
+impl<T, V> NotValidator<T, V>
+where
+    V: Validator<T>,
+{
+    pub fn new(inner: V, description: &'static str) -> Self {
+        Self {
+            inner,
+            description,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, V> Validator<T> for NotValidator<T, V>
+where
+    T: Display,
+    V: Validator<T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if self.inner.validate_first(path, value).is_continue() {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Not,
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: self.description.to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'email' format. Accepts addresses of the form `local@domain` where the local
+/// part consists of the characters allowed by RFC 5322 (without quoting) and the domain of at least
+/// two DNS labels.
+#[derive(Default)]
+pub struct EmailValidator {}
+
+impl EmailValidator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn is_email(value: &str) -> bool {
+        let Some((local, domain)) = value.rsplit_once('@') else {
+            return false;
+        };
+
+        let valid_local = (1..=64).contains(&local.len())
+            && !local.starts_with('.')
+            && !local.ends_with('.')
+            && !local.contains("..")
+            && local
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~.-".contains(c));
+        let valid_domain = domain.len() <= 253
+            && domain.split('.').count() >= 2
+            && domain.split('.').all(|label| {
+                (1..=63).contains(&label.len())
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+
+        valid_local && valid_domain
+    }
+}
+
+impl<T> Validator<T> for EmailValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if !Self::is_email(value) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "email" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "email".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'uuid' format. Accepts hyphenated UUIDs of the form
+/// `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx` with hex digits of any case, optionally enclosed in braces.
+#[derive(Default)]
+pub struct UuidValidator {}
+
+impl UuidValidator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn is_uuid(value: &str) -> bool {
+        let value = value
+            .strip_prefix('{')
+            .and_then(|value| value.strip_suffix('}'))
+            .unwrap_or(value);
+        let groups = value.split('-').collect::<Vec<_>>();
+
+        groups.iter().map(|group| group.len()).eq([8, 4, 4, 4, 12])
+            && groups
+                .iter()
+                .all(|group| group.chars().all(|c| c.is_ascii_hexdigit()))
+    }
+}
+
+impl<T> Validator<T> for UuidValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if !Self::is_uuid(value) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "uuid" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "uuid".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Parses a number of exactly the passed count of ASCII digits.
+fn parse_digits(value: &str, count: usize) -> Option<u32> {
+    if value.len() == count && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Validator for the 'date' format. Accepts RFC 3339 full dates of the form `YYYY-MM-DD` with a
+/// month between 01 and 12 and a day that exists in the month, considering leap years.
+#[derive(Default)]
+pub struct DateValidator {}
+
+impl DateValidator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn is_date(value: &str) -> bool {
+        let mut parts = value.split('-');
+        let (Some(year), Some(month), Some(day), None) =
+            (parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return false;
+        };
+        let (Some(year), Some(month), Some(day)) = (
+            parse_digits(year, 4),
+            parse_digits(month, 2),
+            parse_digits(day, 2),
+        ) else {
+            return false;
+        };
+
+        let is_leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days = match month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if is_leap_year => 29,
+            2 => 28,
+            _ => return false,
+        };
+
+        (1..=days).contains(&day)
+    }
+}
+
+impl<T> Validator<T> for DateValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if !Self::is_date(value) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "date" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "date".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'date-time' format. Accepts RFC 3339 timestamps of the form
+/// `YYYY-MM-DDTHH:MM:SS[.F]Z` or `YYYY-MM-DDTHH:MM:SS[.F]+HH:MM` where the date is accepted by
+/// [DateValidator], `T` and `Z` may be lowercase, the optional fraction has at least one digit, the
+/// offset sign is `+` or `-`, hours are between 00 and 23, minutes between 00 and 59 and seconds
+/// between 00 and 60 to allow leap seconds.
+#[derive(Default)]
+pub struct DateTimeValidator {}
+
+impl DateTimeValidator {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn is_hour_minute(value: &str) -> bool {
+        let Some((hour, minute)) = value.split_once(':') else {
+            return false;
+        };
+
+        matches!(
+            (parse_digits(hour, 2), parse_digits(minute, 2)),
+            (Some(0..=23), Some(0..=59))
+        )
+    }
+
+    fn is_date_time(value: &str) -> bool {
+        let Some((date, time)) = value.split_once(['T', 't']) else {
+            return false;
+        };
+        let (time, offset) = if let Some(time) = time.strip_suffix(['Z', 'z']) {
+            (time, None)
+        } else if let Some(sign) = time.rfind(['+', '-']) {
+            (&time[..sign], Some(&time[sign + 1..]))
+        } else {
+            return false;
+        };
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, Some(fraction)),
+            None => (time, None),
+        };
+        let Some((hour_minute, second)) = time.rsplit_once(':') else {
+            return false;
+        };
+
+        DateValidator::is_date(date)
+            && Self::is_hour_minute(hour_minute)
+            && matches!(parse_digits(second, 2), Some(0..=60))
+            && fraction.is_none_or(|fraction| {
+                !fraction.is_empty() && fraction.bytes().all(|byte| byte.is_ascii_digit())
+            })
+            && offset.is_none_or(Self::is_hour_minute)
+    }
+}
+
+impl<T> Validator<T> for DateTimeValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if !Self::is_date_time(value) {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format {
+                    format: "date-time",
+                },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "date-time".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'ipv4' format. Accepts dotted-decimal IPv4 addresses as parsed by
+/// [std::net::Ipv4Addr].
+#[derive(Default)]
+pub struct Ipv4Validator {}
+
+impl Ipv4Validator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Validator<T> for Ipv4Validator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if value.parse::<std::net::Ipv4Addr>().is_err() {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "ipv4" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "ipv4".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'ipv6' format. Accepts IPv6 addresses as parsed by [std::net::Ipv6Addr].
+#[derive(Default)]
+pub struct Ipv6Validator {}
+
+impl Ipv6Validator {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl<T> Validator<T> for Ipv6Validator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        let value = value.as_ref();
+
+        if value.parse::<std::net::Ipv6Addr>().is_err() {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::Format { format: "ipv6" },
+                path: path.to_string(),
+                index: path.index(),
+                actual: value.to_string(),
+                expected: "ipv6".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Trait for collections whose number of items is checked by the 'max_items' and 'min_items'
+/// schema checks.
+pub trait Collection {
+    /// Type of the items.
+    type Item;
+
+    /// Returns the number of items.
+    fn item_count(&self) -> usize;
+}
+
+impl<T> Collection for Vec<T> {
+    type Item = T;
+
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const N: usize> Collection for [T; N] {
+    type Item = T;
+
+    fn item_count(&self) -> usize {
+        N
+    }
+}
+
+impl<T, S> Collection for HashSet<T, S> {
+    type Item = T;
+
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Collection for BTreeSet<T> {
+    type Item = T;
+
+    fn item_count(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Validator for the 'max_items' schema check.
+pub struct MaxItemsValidator<T> {
+    max_items: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> MaxItemsValidator<T> {
+    pub fn new(max_items: usize) -> Self {
+        Self {
+            max_items,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Validator<C> for MaxItemsValidator<T>
+where
+    C: Collection<Item = T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &C, errors: &mut dyn ErrorSink) {
+        let item_count = value.item_count();
+
+        if item_count > self.max_items {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MaxItems,
+                path: path.to_string(),
+                index: path.index(),
+                actual: item_count.to_string(),
+                expected: self.max_items.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'min_items' schema check.
+pub struct MinItemsValidator<T> {
+    min_items: usize,
+    phantom: PhantomData<T>,
+}
+
+impl<T> MinItemsValidator<T> {
+    pub fn new(min_items: usize) -> Self {
+        Self {
+            min_items,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, C> Validator<C> for MinItemsValidator<T>
+where
+    C: Collection<Item = T>,
+{
+    fn validate(&self, path: &ValidationPath, value: &C, errors: &mut dyn ErrorSink) {
+        let item_count = value.item_count();
+
+        if item_count < self.min_items {
+            errors.push(ValidationError {
+                category: ValidationErrorCategory::MinItems,
+                path: path.to_string(),
+                index: path.index(),
+                actual: item_count.to_string(),
+                expected: self.min_items.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Validator for the 'unique_items' schema check. Reports the first item that is equal to an earlier
+/// item.
+pub struct UniqueItemsValidator<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T> UniqueItemsValidator<T> {
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T> Default for UniqueItemsValidator<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Validator<Vec<T>> for UniqueItemsValidator<T>
+where
+    T: PartialEq,
+{
+    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut dyn ErrorSink) {
+        for (index, item) in value.iter().enumerate() {
+            if let Some(earlier_index) = value[..index].iter().position(|earlier| earlier == item) {
+                errors.push(ValidationError {
+                    category: ValidationErrorCategory::UniqueItems,
+                    path: path.to_string(),
+                    index: path.index(),
+                    actual: index.to_string(),
+                    expected: earlier_index.to_string(),
+                    custom_message: None,
+                    severity: Severity::Error,
+                });
+
+                return;
+            }
+        }
+    }
+}
+
+/// Trait for numbers that can be checked by the 'multiple_of' schema check. Integers are compared
+/// exactly, floats allow a remainder within a small relative tolerance of 0 or of the divisor such
+/// that e.g. 0.3 is a multiple of 0.1 despite rounding errors.
+pub trait Divisible {
+    /// Returns whether this number is a multiple of the divisor.
+    fn is_divisible_by(&self, divisor: &Self) -> bool;
+}
+
+macro_rules! divisible_integer {
+    ($($type:ty),*) => {
+        $(
+            impl Divisible for $type {
+                fn is_divisible_by(&self, divisor: &Self) -> bool {
+                    *self % *divisor == 0
+                }
+            }
+        )*
+    };
+}
+
+divisible_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! divisible_float {
+    ($($type:ty),*) => {
+        $(
+            impl Divisible for $type {
+                fn is_divisible_by(&self, divisor: &Self) -> bool {
+                    let remainder = (*self % *divisor).abs();
+                    let tolerance = <$type>::EPSILON * self.abs().max(divisor.abs());
+
+                    remainder <= tolerance || divisor.abs() - remainder <= tolerance
+                }
+            }
+        )*
+    };
 }
 
-impl<T> Validatable for Vec<T>
+divisible_float!(f32, f64);
+
+/// Validator for the 'multiple_of' schema check, see [Divisible].
+pub struct MultipleOfValidator<T>
 where
-    T: Validatable,
+    T: Divisible + Display,
 {
-    type DefaultValidator = VecValidator<T, T::DefaultValidator>;
-}
-
-/// Validator for the 'exclusive_maximum' schema check.
-pub struct ExclusiveMaximumValidator<T: PartialOrd + Display> {
-    exclusive_maximum: T,
+    multiple_of: T,
+    phantom: PhantomData<T>,
 }
 
-impl<T> ExclusiveMaximumValidator<T>
+impl<T> MultipleOfValidator<T>
 where
-    T: PartialOrd + Display,
+    T: Divisible + Display,
 {
-    pub fn new(exclusive_maximum: T) -> Self {
-        Self { exclusive_maximum }
+    pub fn new(multiple_of: T) -> Self {
+        Self {
+            multiple_of,
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<T> Validator<T> for ExclusiveMaximumValidator<T>
+impl<T> Validator<T> for MultipleOfValidator<T>
 where
-    T: PartialOrd + Display,
+    T: Divisible + Display,
 {
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>) {
-        if *value >= self.exclusive_maximum {
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if !value.is_divisible_by(&self.multiple_of) {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::ExclusiveMaximum,
+                category: ValidationErrorCategory::MultipleOf,
                 path: path.to_string(),
+                index: path.index(),
                 actual: value.to_string(),
-                expected: self.exclusive_maximum.to_string(),
+                expected: self.multiple_of.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'exclusive_minimum' schema check.
-pub struct ExclusiveMinimumValidator<T: PartialOrd + Display> {
-    exclusive_minimum: T,
+/// Validator for the 'const' schema check. The constant may be of a different type than the value,
+/// e.g. a string literal for String values.
+pub struct ConstValidator<C>
+where
+    C: Display,
+{
+    value: C,
 }
 
-impl<T> ExclusiveMinimumValidator<T>
+impl<C> ConstValidator<C>
 where
-    T: PartialOrd + Display,
+    C: Display,
 {
-    pub fn new(exclusive_minimum: T) -> Self {
-        Self { exclusive_minimum }
+    pub fn new(value: C) -> Self {
+        Self { value }
     }
 }
 
-impl<T> Validator<T> for ExclusiveMinimumValidator<T>
+impl<C, T> Validator<T> for ConstValidator<C>
 where
-    T: PartialOrd + Display,
+    C: Display,
+    T: PartialEq<C> + Display,
 {
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>) {
-        if *value <= self.exclusive_minimum {
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if *value != self.value {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::ExclusiveMinimum,
+                category: ValidationErrorCategory::Const,
                 path: path.to_string(),
+                index: path.index(),
                 actual: value.to_string(),
-                expected: self.exclusive_minimum.to_string(),
+                expected: self.value.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'maximum' schema check.
-pub struct MaximumValidator<T: PartialOrd + Display> {
-    maximum: T,
+/// Validator for the 'enumeration' schema check. The expected value of errors lists the allowed
+/// values separated by commas.
+pub struct EnumerationValidator<C, const N: usize>
+where
+    C: Display,
+{
+    values: [C; N],
 }
 
-impl<T> MaximumValidator<T>
+impl<C, const N: usize> EnumerationValidator<C, N>
 where
-    T: PartialOrd + Display,
+    C: Display,
 {
-    pub fn new(maximum: T) -> Self {
-        Self { maximum }
+    pub fn new(values: [C; N]) -> Self {
+        Self { values }
     }
 }
 
-impl<T> Validator<T> for MaximumValidator<T>
+impl<C, T, const N: usize> Validator<T> for EnumerationValidator<C, N>
 where
-    T: PartialOrd + Display,
+    C: Display,
+    T: PartialEq<C> + Display,
 {
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>) {
-        if *value > self.maximum {
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if !self.values.iter().any(|allowed| *value == *allowed) {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::Maximum,
+                category: ValidationErrorCategory::Enumeration,
                 path: path.to_string(),
+                index: path.index(),
                 actual: value.to_string(),
-                expected: self.maximum.to_string(),
+                expected: self
+                    .values
+                    .iter()
+                    .map(|allowed| allowed.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'minimum' schema check.
-pub struct MinimumValidator<T: PartialOrd + Display> {
-    minimum: T,
-}
+/// Validator for `#[validate(finite)]` that rejects NaN and infinite floats.
+#[derive(Default)]
+pub struct FiniteValidator {}
 
-impl<T> MinimumValidator<T>
-where
-    T: PartialOrd + Display,
-{
-    pub fn new(minimum: T) -> Self {
-        Self { minimum }
+impl FiniteValidator {
+    pub fn new() -> Self {
+        Self {}
     }
 }
 
-impl<T> Validator<T> for MinimumValidator<T>
-where
-    T: PartialOrd + Display,
-{
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>) {
-        if *value < self.minimum {
-            errors.push(ValidationError {
-                category: ValidationErrorCategory::Minimum,
-                path: path.to_string(),
-                actual: value.to_string(),
-                expected: self.minimum.to_string(),
-            });
+macro_rules! finite_validator {
+    ($type:ty) => {
+        impl Validator<$type> for FiniteValidator {
+            fn validate(&self, path: &ValidationPath, value: &$type, errors: &mut dyn ErrorSink) {
+                if !value.is_finite() {
+                    errors.push(ValidationError {
+                        category: ValidationErrorCategory::Finite,
+                        path: path.to_string(),
+                        index: path.index(),
+                        actual: value.to_string(),
+                        expected: "finite".to_owned(),
+                        custom_message: None,
+                        severity: Severity::Error,
+                    });
+                }
+            }
         }
-    }
+    };
 }
 
-/// Validator for the 'max_length' schema check.
-pub struct MaxLengthValidator {
-    max_length: usize,
-}
+finite_validator!(f32);
+finite_validator!(f64);
 
-impl MaxLengthValidator {
-    pub fn new(max_length: usize) -> Self {
-        Self { max_length }
+/// Validator for `#[validate(ascii)]` that rejects strings with non-ASCII characters.
+#[derive(Default)]
+pub struct AsciiValidator {}
+
+impl AsciiValidator {
+    pub fn new() -> Self {
+        Self {}
     }
 }
 
-impl Validator<String> for MaxLengthValidator {
-    fn validate(&self, path: &ValidationPath, value: &String, errors: &mut Vec<ValidationError>) {
-        if value.len() > self.max_length {
+impl<T> Validator<T> for AsciiValidator
+where
+    T: AsRef<str>,
+{
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        if let Some(character) = value
+            .as_ref()
+            .chars()
+            .find(|character| !character.is_ascii())
+        {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::MaxLength,
+                category: ValidationErrorCategory::Ascii,
                 path: path.to_string(),
-                actual: value.len().to_string(),
-                expected: self.max_length.to_string(),
+                index: path.index(),
+                actual: character.to_string(),
+                expected: "ASCII".to_owned(),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'min_length' schema check.
-pub struct MinLengthValidator {
-    min_length: usize,
+/// Validator for the 'max_properties' schema check.
+pub struct MaxPropertiesValidator<K, T> {
+    max_properties: usize,
+    phantom: PhantomData<(K, T)>,
 }
 
-impl MinLengthValidator {
-    pub fn new(min_length: usize) -> Self {
-        Self { min_length }
+impl<K, T> MaxPropertiesValidator<K, T> {
+    pub fn new(max_properties: usize) -> Self {
+        Self {
+            max_properties,
+            phantom: PhantomData,
+        }
     }
-}
 
-impl Validator<String> for MinLengthValidator {
-    fn validate(&self, path: &ValidationPath, value: &String, errors: &mut Vec<ValidationError>) {
-        if value.len() < self.min_length {
+    fn validate_len(&self, path: &ValidationPath, len: usize, errors: &mut dyn ErrorSink) {
+        if len > self.max_properties {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::MinLength,
+                category: ValidationErrorCategory::MaxProperties,
                 path: path.to_string(),
-                actual: value.len().to_string(),
-                expected: self.min_length.to_string(),
+                index: path.index(),
+                actual: len.to_string(),
+                expected: self.max_properties.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'pattern' schema check.
-pub struct PatternValidator {
-    pattern: Regex,
-}
-
-impl PatternValidator {
-    pub fn new(pattern: Regex) -> Self {
-        Self { pattern }
+impl<K, T, S> Validator<HashMap<K, T, S>> for MaxPropertiesValidator<K, T> {
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_len(path, value.len(), errors);
     }
 }
 
-impl Validator<String> for PatternValidator {
-    fn validate(&self, path: &ValidationPath, value: &String, errors: &mut Vec<ValidationError>) {
-        if !self.pattern.is_match(value) {
-            errors.push(ValidationError {
-                category: ValidationErrorCategory::Pattern,
-                path: path.to_string(),
-                actual: value.to_string(),
-                expected: self.pattern.to_string(),
-            });
-        }
+impl<K, T> Validator<BTreeMap<K, T>> for MaxPropertiesValidator<K, T> {
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.validate_len(path, value.len(), errors);
     }
 }
 
-/// Validator for the 'max_items' schema check.
-pub struct MaxItemsValidator<T> {
-    max_items: usize,
-    phantom: PhantomData<T>,
+/// Validator for the 'min_properties' schema check.
+pub struct MinPropertiesValidator<K, T> {
+    min_properties: usize,
+    phantom: PhantomData<(K, T)>,
 }
 
-impl<T> MaxItemsValidator<T> {
-    pub fn new(max_items: usize) -> Self {
+impl<K, T> MinPropertiesValidator<K, T> {
+    pub fn new(min_properties: usize) -> Self {
         Self {
-            max_items,
-            phantom: PhantomData::default(),
+            min_properties,
+            phantom: PhantomData,
         }
     }
-}
 
-impl<T> Validator<Vec<T>> for MaxItemsValidator<T> {
-    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut Vec<ValidationError>) {
-        if value.len() > self.max_items {
+    fn validate_len(&self, path: &ValidationPath, len: usize, errors: &mut dyn ErrorSink) {
+        if len < self.min_properties {
             errors.push(ValidationError {
-                category: ValidationErrorCategory::MaxItems,
+                category: ValidationErrorCategory::MinProperties,
                 path: path.to_string(),
-                actual: value.len().to_string(),
-                expected: self.max_items.to_string(),
+                index: path.index(),
+                actual: len.to_string(),
+                expected: self.min_properties.to_string(),
+                custom_message: None,
+                severity: Severity::Error,
             });
         }
     }
 }
 
-/// Validator for the 'min_items' schema check.
-pub struct MinItemsValidator<T> {
-    min_items: usize,
-    phantom: PhantomData<T>,
+impl<K, T, S> Validator<HashMap<K, T, S>> for MinPropertiesValidator<K, T> {
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_len(path, value.len(), errors);
+    }
 }
 
-impl<T> MinItemsValidator<T> {
-    pub fn new(min_items: usize) -> Self {
+impl<K, T> Validator<BTreeMap<K, T>> for MinPropertiesValidator<K, T> {
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.validate_len(path, value.len(), errors);
+    }
+}
+
+/// Validator for maps that only allows the configured keys. Every other key is reported with an
+/// [ValidationErrorCategory::Other] error tagged "allowed_keys".
+pub struct AllowedKeysValidator<K, T> {
+    allowed_keys: &'static [&'static str],
+    phantom: PhantomData<(K, T)>,
+}
+
+impl<K, T> AllowedKeysValidator<K, T>
+where
+    K: Display,
+{
+    pub fn new(allowed_keys: &'static [&'static str]) -> Self {
         Self {
-            min_items,
-            phantom: PhantomData::default(),
+            allowed_keys,
+            phantom: PhantomData,
         }
     }
-}
 
-impl<T> Validator<Vec<T>> for MinItemsValidator<T> {
-    fn validate(&self, path: &ValidationPath, value: &Vec<T>, errors: &mut Vec<ValidationError>) {
-        if value.len() < self.min_items {
-            errors.push(ValidationError {
-                category: ValidationErrorCategory::MinItems,
-                path: path.to_string(),
-                actual: value.len().to_string(),
-                expected: self.min_items.to_string(),
-            });
+    fn validate_keys<'a>(
+        &self,
+        path: &ValidationPath,
+        keys: impl Iterator<Item = &'a K>,
+        errors: &mut dyn ErrorSink,
+    ) where
+        K: 'a,
+    {
+        for key in keys {
+            let key = key.to_string();
+
+            if !self.allowed_keys.contains(&key.as_str()) {
+                let key_path = ValidationPath::Key {
+                    parent: path,
+                    key: &key,
+                };
+
+                errors.push(ValidationError {
+                    category: ValidationErrorCategory::Other {
+                        tag: "allowed_keys",
+                        display: |error, f| {
+                            write!(
+                                f,
+                                "{}: Must be one of the keys {} but is {}",
+                                error.path, error.expected, error.actual
+                            )
+                        },
+                        detail: None,
+                    },
+                    path: key_path.to_string(),
+                    index: key_path.index(),
+                    actual: key.clone(),
+                    expected: self.allowed_keys.join(", "),
+                    custom_message: None,
+                    severity: Severity::Error,
+                });
+            }
         }
     }
 }
 
-/// Validator for the 'multiple_of' schema check.
-pub struct MultipleOfValidator<T>
+impl<K, T, S> Validator<HashMap<K, T, S>> for AllowedKeysValidator<K, T>
 where
-    T: Rem<T, Output = T> + PartialEq + Default + Copy + Display,
+    K: Display,
 {
-    multiple_of: T,
-    phantom: PhantomData<T>,
+    fn validate(
+        &self,
+        path: &ValidationPath,
+        value: &HashMap<K, T, S>,
+        errors: &mut dyn ErrorSink,
+    ) {
+        self.validate_keys(path, value.keys(), errors);
+    }
 }
 
-impl<T> MultipleOfValidator<T>
+impl<K, T> Validator<BTreeMap<K, T>> for AllowedKeysValidator<K, T>
 where
-    T: Rem<T, Output = T> + PartialEq + Default + Copy + Display,
+    K: Display,
 {
-    pub fn new(multiple_of: T) -> Self {
+    fn validate(&self, path: &ValidationPath, value: &BTreeMap<K, T>, errors: &mut dyn ErrorSink) {
+        self.validate_keys(path, value.keys(), errors);
+    }
+}
+
+/// A validator that checks values against the constraints of a JSON schema generated by the
+/// `schemars` crate. The value is serialized into JSON first.
+///
+/// The number, string, array and object keywords are checked, `$ref` and `allOf` are followed.
+/// Other combinators like `anyOf` are not evaluated. The patterns of the schema are compiled once
+/// when the validator is created, invalid patterns are reported as errors of the checked strings.
+#[cfg(feature = "schemars")]
+pub struct SchemarsValidator<T> {
+    schema: schemars::schema::RootSchema,
+    patterns: HashMap<String, FalliblePatternValidator>,
+    phantom: PhantomData<fn(&T)>,
+}
+
+#[cfg(feature = "schemars")]
+impl<T> SchemarsValidator<T> {
+    pub fn new(schema: schemars::schema::RootSchema) -> Self {
+        let mut patterns = HashMap::new();
+        Self::collect_patterns(&schema.schema, &mut patterns);
+        for definition in schema.definitions.values() {
+            Self::collect_schema_patterns(definition, &mut patterns);
+        }
+
         Self {
-            multiple_of,
-            phantom: PhantomData::default(),
+            schema,
+            patterns,
+            phantom: PhantomData,
+        }
+    }
+
+    fn collect_schema_patterns(
+        schema: &schemars::schema::Schema,
+        patterns: &mut HashMap<String, FalliblePatternValidator>,
+    ) {
+        if let schemars::schema::Schema::Object(schema) = schema {
+            Self::collect_patterns(schema, patterns);
+        }
+    }
+
+    fn collect_patterns(
+        schema: &schemars::schema::SchemaObject,
+        patterns: &mut HashMap<String, FalliblePatternValidator>,
+    ) {
+        if let Some(pattern) = schema.string.as_ref().and_then(|s| s.pattern.as_ref()) {
+            patterns
+                .entry(pattern.clone())
+                .or_insert_with(|| FalliblePatternValidator::new(pattern));
+        }
+
+        if let Some(array) = &schema.array {
+            match &array.items {
+                Some(schemars::schema::SingleOrVec::Single(item_schema)) => {
+                    Self::collect_schema_patterns(item_schema, patterns);
+                }
+                Some(schemars::schema::SingleOrVec::Vec(item_schemas)) => {
+                    for item_schema in item_schemas {
+                        Self::collect_schema_patterns(item_schema, patterns);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if let Some(object) = &schema.object {
+            for property_schema in object
+                .properties
+                .values()
+                .chain(object.additional_properties.as_deref())
+            {
+                Self::collect_schema_patterns(property_schema, patterns);
+            }
+        }
+
+        if let Some(all_of) = schema.subschemas.as_ref().and_then(|s| s.all_of.as_ref()) {
+            for subschema in all_of {
+                Self::collect_schema_patterns(subschema, patterns);
+            }
+        }
+    }
+
+    fn validate_schema(
+        &self,
+        path: &ValidationPath,
+        schema: &schemars::schema::Schema,
+        value: &serde_json::Value,
+        errors: &mut dyn ErrorSink,
+    ) {
+        if let schemars::schema::Schema::Object(schema) = schema {
+            self.validate_schema_object(path, schema, value, errors);
+        }
+    }
+
+    fn validate_schema_object(
+        &self,
+        path: &ValidationPath,
+        schema: &schemars::schema::SchemaObject,
+        value: &serde_json::Value,
+        errors: &mut dyn ErrorSink,
+    ) {
+        let error = |category, actual: String, expected: String| ValidationError {
+            category,
+            path: path.to_string(),
+            actual,
+            expected,
+            index: path.index(),
+            custom_message: None,
+            severity: Severity::Error,
+        };
+
+        if let (Some(number), Some(actual)) = (&schema.number, value.as_f64()) {
+            if let Some(expected) = number.exclusive_maximum.filter(|bound| actual >= *bound) {
+                errors.push(error(
+                    ValidationErrorCategory::ExclusiveMaximum,
+                    value.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = number.exclusive_minimum.filter(|bound| actual <= *bound) {
+                errors.push(error(
+                    ValidationErrorCategory::ExclusiveMinimum,
+                    value.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = number.maximum.filter(|bound| actual > *bound) {
+                errors.push(error(
+                    ValidationErrorCategory::Maximum,
+                    value.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = number.minimum.filter(|bound| actual < *bound) {
+                errors.push(error(
+                    ValidationErrorCategory::Minimum,
+                    value.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = number
+                .multiple_of
+                .filter(|divisor| !actual.is_divisible_by(divisor))
+            {
+                errors.push(error(
+                    ValidationErrorCategory::MultipleOf,
+                    value.to_string(),
+                    expected.to_string(),
+                ));
+            }
+        }
+
+        if let (Some(string), Some(actual)) = (&schema.string, value.as_str()) {
+            let length = actual.chars().count();
+
+            if let Some(expected) = string.max_length.filter(|max| length > *max as usize) {
+                errors.push(error(
+                    ValidationErrorCategory::MaxLength,
+                    length.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = string.min_length.filter(|min| length < *min as usize) {
+                errors.push(error(
+                    ValidationErrorCategory::MinLength,
+                    length.to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(pattern) = string
+                .pattern
+                .as_ref()
+                .and_then(|pattern| self.patterns.get(pattern))
+            {
+                pattern.validate(path, &actual, errors);
+            }
+        }
+
+        if let (Some(array), Some(items)) = (&schema.array, value.as_array()) {
+            if let Some(expected) = array.max_items.filter(|max| items.len() > *max as usize) {
+                errors.push(error(
+                    ValidationErrorCategory::MaxItems,
+                    items.len().to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = array.min_items.filter(|min| items.len() < *min as usize) {
+                errors.push(error(
+                    ValidationErrorCategory::MinItems,
+                    items.len().to_string(),
+                    expected.to_string(),
+                ));
+            }
+        }
+
+        if let (Some(object), Some(properties)) = (&schema.object, value.as_object()) {
+            if let Some(expected) = object
+                .max_properties
+                .filter(|max| properties.len() > *max as usize)
+            {
+                errors.push(error(
+                    ValidationErrorCategory::MaxProperties,
+                    properties.len().to_string(),
+                    expected.to_string(),
+                ));
+            }
+            if let Some(expected) = object
+                .min_properties
+                .filter(|min| properties.len() < *min as usize)
+            {
+                errors.push(error(
+                    ValidationErrorCategory::MinProperties,
+                    properties.len().to_string(),
+                    expected.to_string(),
+                ));
+            }
+        }
+
+        if let (Some(array), Some(items)) = (&schema.array, value.as_array()) {
+            match &array.items {
+                Some(schemars::schema::SingleOrVec::Single(item_schema)) => {
+                    for (index, item) in items.iter().enumerate() {
+                        let item_path = ValidationPath::Item {
+                            parent: path,
+                            index,
+                        };
+                        self.validate_schema(&item_path, item_schema, item, errors);
+                    }
+                }
+                Some(schemars::schema::SingleOrVec::Vec(item_schemas)) => {
+                    for (index, (item_schema, item)) in item_schemas.iter().zip(items).enumerate() {
+                        let item_path = ValidationPath::Item {
+                            parent: path,
+                            index,
+                        };
+                        self.validate_schema(&item_path, item_schema, item, errors);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        if let (Some(object), Some(properties)) = (&schema.object, value.as_object()) {
+            for (name, property) in properties {
+                let property_schema = object
+                    .properties
+                    .get(name)
+                    .or(object.additional_properties.as_deref());
+
+                if let Some(property_schema) = property_schema {
+                    let field_path = ValidationPath::Field { parent: path, name };
+                    self.validate_schema(&field_path, property_schema, property, errors);
+                }
+            }
+        }
+
+        if let Some(all_of) = schema.subschemas.as_ref().and_then(|s| s.all_of.as_ref()) {
+            for subschema in all_of {
+                self.validate_schema(path, subschema, value, errors);
+            }
+        }
+
+        if let Some(reference) = &schema.reference {
+            let definition = reference
+                .strip_prefix("#/definitions/")
+                .and_then(|name| self.schema.definitions.get(name));
+
+            if let Some(definition) = definition {
+                self.validate_schema(path, definition, value, errors);
+            }
         }
     }
 }
 
-impl<T> Validator<T> for MultipleOfValidator<T>
+#[cfg(feature = "schemars")]
+impl<T> Validator<T> for SchemarsValidator<T>
 where
-    T: Rem<T, Output = T> + PartialEq + Default + Copy + Display,
+    T: serde::Serialize,
 {
-    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut Vec<ValidationError>) {
-        if *value % self.multiple_of != T::default() {
-            errors.push(ValidationError {
-                category: ValidationErrorCategory::MultipleOf,
+    fn validate(&self, path: &ValidationPath, value: &T, errors: &mut dyn ErrorSink) {
+        match serde_json::to_value(value) {
+            Ok(value) => self.validate_schema_object(path, &self.schema.schema, &value, errors),
+            Err(error) => errors.push(ValidationError {
+                category: ValidationErrorCategory::Other {
+                    tag: "serialization",
+                    display: |error, f| {
+                        write!(
+                            f,
+                            "{}: Could not be serialized: {}",
+                            error.path, error.actual
+                        )
+                    },
+                    detail: None,
+                },
                 path: path.to_string(),
-                actual: value.to_string(),
-                expected: self.multiple_of.to_string(),
-            });
+                index: path.index(),
+                actual: error.to_string(),
+                expected: String::new(),
+                custom_message: None,
+                severity: Severity::Error,
+            }),
         }
     }
 }
@@ -0,0 +1,12 @@
+#[test]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/*.rs");
+}
+
+#[cfg(not(feature = "regex"))]
+#[test]
+fn compile_fail_no_regex() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail_no_regex/*.rs");
+}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Dates {
+    #[validate(minimum = "2023-02-30")]
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Name {
+    #[validate(char_length(mn = 1))]
+    value: String,
+}
+
+fn main() {}
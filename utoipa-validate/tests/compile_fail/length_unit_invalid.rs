@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Name {
+    #[validate(max_length = 4, length = "words")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use utoipa_validate::{MinLengthValidator, Validatable};
+
+#[derive(Validatable)]
+struct Overridable {
+    first: i32,
+}
+
+fn main() {
+    let _ = OverridableValidator::default().with_first_override(MinLengthValidator::new(1));
+}
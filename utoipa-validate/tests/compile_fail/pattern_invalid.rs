@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Hex {
+    #[validate(pattern = "^[0-9a-f+$")]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Password {
+    #[validate(pattern = "^(?=.*[0-9]).{8,}$")]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+union Number {
+    integer: i32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Value {
+    #[validate(minimun = 1)]
+    value: i32,
+}
+
+fn main() {}
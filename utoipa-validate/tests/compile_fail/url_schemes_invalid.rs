@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Link {
+    #[validate(url(scheme("https")))]
+    value: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use utoipa_validate::Validatable;
+
+#[derive(Validatable)]
+struct Even {
+    #[validate(with = "validators::even(")]
+    value: i32,
+}
+
+fn main() {}
@@ -0,0 +1,267 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+use utoipa_validate::{
+    PathSegment, Severity, ValidationError, ValidationErrorCategory, ValidationPath,
+};
+
+fn error(path: &str) -> ValidationError {
+    ValidationError {
+        category: ValidationErrorCategory::Minimum,
+        path: path.to_owned(),
+        actual: "0".to_owned(),
+        expected: "1".to_owned(),
+        index: None,
+        custom_message: None,
+        severity: Severity::Error,
+    }
+}
+
+#[test]
+fn with_path_prefix() {
+    assert_eq!(error("c").with_path_prefix("b").path, "b.c");
+    assert_eq!(error("c.d").with_path_prefix("a.b").path, "a.b.c.d");
+    assert_eq!(error("[2].c").with_path_prefix("b").path, "b[2].c");
+    assert_eq!(error("").with_path_prefix("b").path, "b");
+    assert_eq!(error("c").with_path_prefix("").path, "c");
+
+    let errors = ValidationError::prefix_paths(&[error("c"), error("[0]")], "b");
+
+    assert_eq!(errors, vec![error("b.c"), error("b[0]")]);
+}
+
+#[test]
+fn message_and_code() {
+    assert_eq!(
+        error("a").message(),
+        "a: Must be greater than or equal to 1 but is 0"
+    );
+    assert_eq!(error("a").code(), "minimum");
+
+    let other = ValidationError {
+        category: ValidationErrorCategory::Other {
+            tag: "custom",
+            display: |error, f| write!(f, "{}: Custom", error.path),
+            detail: None,
+        },
+        ..error("b")
+    };
+    assert_eq!(other.message(), "b: Custom");
+    assert_eq!(other.code(), "custom");
+}
+
+#[test]
+fn pattern_source() {
+    let pattern_error = ValidationError {
+        category: ValidationErrorCategory::Pattern,
+        path: "hex".to_owned(),
+        actual: "abz".to_owned(),
+        expected: "^[0-9a-f]+$".to_owned(),
+        index: None,
+        custom_message: None,
+        severity: Severity::Error,
+    };
+
+    assert_eq!(pattern_error.pattern_source(), Some("^[0-9a-f]+$"));
+    assert_eq!(error("c").pattern_source(), None);
+}
+
+#[test]
+fn depth() {
+    assert_eq!(error("").depth(), 0);
+    assert_eq!(error("a").depth(), 1);
+    assert_eq!(error("[0]").depth(), 1);
+    assert_eq!(error("a.b[2].c").depth(), 4);
+    assert_eq!(error("map[\"a.b[0]\"]").depth(), 2);
+}
+
+#[test]
+fn path_depth() {
+    let a = ValidationPath::Field {
+        parent: &ValidationPath::Root,
+        name: "a",
+    };
+    let b = ValidationPath::Field {
+        parent: &a,
+        name: "b",
+    };
+    let item = ValidationPath::Item {
+        parent: &b,
+        index: 0,
+    };
+
+    assert_eq!(ValidationPath::Root.depth(), 0);
+    assert_eq!(item.to_string(), "a.b[0]");
+    assert_eq!(item.depth(), 3);
+}
+
+#[test]
+fn key_path() {
+    let map = ValidationPath::Field {
+        parent: &ValidationPath::Root,
+        name: "map",
+    };
+    let key = ValidationPath::Key {
+        parent: &map,
+        key: "1",
+    };
+    let quoted = ValidationPath::Key {
+        parent: &map,
+        key: "a\"b\\",
+    };
+
+    assert_eq!(key.to_string(), "map[\"1\"]");
+    assert_eq!(quoted.to_string(), "map[\"a\\\"b\\\\\"]");
+    assert_eq!(key.depth(), 2);
+
+    let field = ValidationPath::Field {
+        parent: &map,
+        name: "a.b[0]",
+    };
+
+    assert_eq!(field.to_string(), "map[\"a.b[0]\"]");
+    assert_eq!(
+        error(&field.to_string()).path_segments(),
+        vec![
+            PathSegment::Field("map".to_owned()),
+            PathSegment::Key("a.b[0]".to_owned())
+        ]
+    );
+}
+
+#[test]
+fn path_segments() {
+    assert_eq!(error("").path_segments(), vec![]);
+    assert_eq!(
+        error("a.b[2][\"1\"].c").path_segments(),
+        vec![
+            PathSegment::Field("a".to_owned()),
+            PathSegment::Field("b".to_owned()),
+            PathSegment::Item(2),
+            PathSegment::Key("1".to_owned()),
+            PathSegment::Field("c".to_owned()),
+        ]
+    );
+    assert_eq!(
+        error("[0][\"a\\\"b.[\"]").path_segments(),
+        vec![PathSegment::Item(0), PathSegment::Key("a\"b.[".to_owned())]
+    );
+
+    for path in ["items[0].map[\"x.y\"]", "[\"a\\\\\"][1]"] {
+        let segments = error(path).path_segments();
+        let rendered =
+            PathSegment::with_path(&ValidationPath::Root, &segments, |path| path.to_string());
+
+        assert_eq!(rendered, path);
+    }
+}
+
+#[test]
+fn one_based() {
+    let error = error("items[2].values[0]");
+
+    assert_eq!(
+        error.one_based().to_string(),
+        "items[3].values[1]: Must be greater than or equal to 1 but is 0"
+    );
+    assert_eq!(error.path, "items[2].values[0]");
+
+    let error = self::error("map[\"2\"].items[0]");
+
+    assert_eq!(
+        error.one_based().to_string(),
+        "map[\"2\"].items[1]: Must be greater than or equal to 1 but is 0"
+    );
+}
+
+#[test]
+fn hash_set() {
+    fn display(_: &ValidationError, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "other")
+    }
+
+    let other = ValidationError {
+        category: ValidationErrorCategory::Other {
+            tag: "other",
+            display,
+            detail: None,
+        },
+        ..error("a")
+    };
+    let errors = HashSet::from([error("a"), error("b"), error("a"), other.clone(), other]);
+
+    assert_eq!(errors.len(), 3);
+    assert!(errors.contains(&error("b")));
+}
+
+#[test]
+fn downcast_detail() {
+    #[derive(Debug, PartialEq)]
+    struct Allowed(Vec<&'static str>);
+
+    let other = ValidationError {
+        category: ValidationErrorCategory::Other {
+            tag: "allowed",
+            display: |error, f| write!(f, "{}: Not allowed", error.path),
+            detail: Some(Arc::new(Allowed(vec!["a", "b"]))),
+        },
+        ..error("a")
+    };
+
+    assert_eq!(
+        other.downcast_detail::<Allowed>(),
+        Some(&Allowed(vec!["a", "b"]))
+    );
+    assert_eq!(other.downcast_detail::<String>(), None);
+    assert_eq!(error("a").downcast_detail::<Allowed>(), None);
+
+    let without_detail = ValidationError {
+        category: ValidationErrorCategory::Other {
+            tag: "allowed",
+            display: |error, f| write!(f, "{}: Not allowed", error.path),
+            detail: None,
+        },
+        ..error("a")
+    };
+    assert_eq!(other.clone(), without_detail);
+    assert_eq!(
+        other.clone().downcast_detail::<Allowed>().unwrap().0.len(),
+        2
+    );
+}
+
+#[test]
+fn sort() {
+    let maximum = ValidationError {
+        category: ValidationErrorCategory::Maximum,
+        ..error("a")
+    };
+    let mut errors = vec![error("b"), error("a"), maximum.clone(), error("[0]")];
+
+    errors.sort();
+
+    assert_eq!(errors, vec![error("[0]"), maximum, error("a"), error("b")]);
+}
+
+#[test]
+fn summarize() {
+    let max_length = ValidationError {
+        category: ValidationErrorCategory::MaxLength,
+        ..error("a")
+    };
+    let pattern = ValidationError {
+        category: ValidationErrorCategory::Pattern,
+        ..error("b")
+    };
+    let errors = [
+        max_length.clone(),
+        max_length.clone(),
+        max_length,
+        pattern,
+        error("c"),
+    ];
+
+    assert_eq!(
+        ValidationError::summarize(&errors),
+        BTreeMap::from([("max_length", 3), ("minimum", 1), ("pattern", 1)])
+    );
+}
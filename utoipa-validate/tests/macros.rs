@@ -1,5 +1,20 @@
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::ops::ControlFlow;
+use std::rc::Rc;
+use std::sync::Arc;
 use utoipa::ToSchema;
-use utoipa_validate::{Validatable, ValidationError, ValidationErrorCategory};
+use utoipa_validate::{
+    debug_validate, impl_transparent_validatable, validate_all, AlwaysValidValidator,
+    CellValidator, ErrorSink, MaximumValidator, MinimumValidator, Patch, Severity,
+    TransparentValidator, Validatable, ValidateChanged, ValidationError, ValidationErrorCategory,
+    ValidationPath, Validator,
+};
+#[cfg(feature = "regex")]
+use utoipa_validate::{FieldConstraint, Normalizable};
 
 #[derive(ToSchema, Validatable)]
 struct IntegerFields {
@@ -76,6 +91,9 @@ fn invalid_integer_fields() {
             path: "signed8".to_owned(),
             actual: "-2".to_owned(),
             expected: "-1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -85,6 +103,9 @@ fn invalid_integer_fields() {
             path: "signed16".to_owned(),
             actual: "-3".to_owned(),
             expected: "-2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -94,6 +115,9 @@ fn invalid_integer_fields() {
             path: "signed32".to_owned(),
             actual: "-4".to_owned(),
             expected: "-3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -103,6 +127,9 @@ fn invalid_integer_fields() {
             path: "signed64".to_owned(),
             actual: "-4".to_owned(),
             expected: "-4".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -112,6 +139,9 @@ fn invalid_integer_fields() {
             path: "unsigned8".to_owned(),
             actual: "0".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -121,6 +151,9 @@ fn invalid_integer_fields() {
             path: "unsigned16".to_owned(),
             actual: "1".to_owned(),
             expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -130,6 +163,9 @@ fn invalid_integer_fields() {
             path: "unsigned32".to_owned(),
             actual: "1".to_owned(),
             expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -139,6 +175,9 @@ fn invalid_integer_fields() {
             path: "unsigned64".to_owned(),
             actual: "4".to_owned(),
             expected: "4".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 
@@ -164,6 +203,9 @@ fn invalid_integer_fields() {
             path: "signed8".to_owned(),
             actual: "4".to_owned(),
             expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -173,6 +215,9 @@ fn invalid_integer_fields() {
             path: "signed16".to_owned(),
             actual: "13".to_owned(),
             expected: "12".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -182,6 +227,9 @@ fn invalid_integer_fields() {
             path: "signed32".to_owned(),
             actual: "15".to_owned(),
             expected: "14".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -191,6 +239,9 @@ fn invalid_integer_fields() {
             path: "signed64".to_owned(),
             actual: "8".to_owned(),
             expected: "8".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -200,6 +251,9 @@ fn invalid_integer_fields() {
             path: "unsigned8".to_owned(),
             actual: "7".to_owned(),
             expected: "6".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -209,6 +263,9 @@ fn invalid_integer_fields() {
             path: "unsigned16".to_owned(),
             actual: "17".to_owned(),
             expected: "16".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -218,6 +275,9 @@ fn invalid_integer_fields() {
             path: "unsigned32".to_owned(),
             actual: "3".to_owned(),
             expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -227,6 +287,9 @@ fn invalid_integer_fields() {
             path: "unsigned64".to_owned(),
             actual: "6".to_owned(),
             expected: "6".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
@@ -276,6 +339,9 @@ fn invalid_float_fields() {
             path: "float32".to_owned(),
             actual: "0.9".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -285,6 +351,9 @@ fn invalid_float_fields() {
             path: "float64".to_owned(),
             actual: "-0.5".to_owned(),
             expected: "-0.5".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 
@@ -304,6 +373,9 @@ fn invalid_float_fields() {
             path: "float32".to_owned(),
             actual: "3".to_owned(),
             expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -313,10 +385,14 @@ fn invalid_float_fields() {
             path: "float64".to_owned(),
             actual: "0.1".to_owned(),
             expected: "0".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
 
+#[cfg(feature = "regex")]
 #[derive(ToSchema, Validatable)]
 struct StringFields {
     #[schema(min_length = 1, max_length = 5)]
@@ -325,6 +401,7 @@ struct StringFields {
     pub hex: String,
 }
 
+#[cfg(feature = "regex")]
 #[test]
 fn valid_string_fields() {
     let result = StringFields {
@@ -344,6 +421,7 @@ fn valid_string_fields() {
     assert!(result.is_ok());
 }
 
+#[cfg(feature = "regex")]
 #[test]
 fn invalid_string_fields() {
     let result = StringFields {
@@ -362,6 +440,9 @@ fn invalid_string_fields() {
             path: "s".to_owned(),
             actual: "0".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -371,6 +452,9 @@ fn invalid_string_fields() {
             path: "hex".to_owned(),
             actual: "".to_owned(),
             expected: "^[0-9a-f]+$".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 
@@ -390,6 +474,9 @@ fn invalid_string_fields() {
             path: "s".to_owned(),
             actual: "6".to_owned(),
             expected: "5".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
     assert_eq!(
@@ -399,11 +486,14 @@ fn invalid_string_fields() {
             path: "hex".to_owned(),
             actual: "abz".to_owned(),
             expected: "^[0-9a-f]+$".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
 
-#[derive(ToSchema, Validatable)]
+#[derive(ToSchema, Validatable, PartialEq, Eq, PartialOrd, Ord)]
 struct UnnamedOption(#[schema(minimum = 3)] Option<i32>);
 
 #[test]
@@ -431,6 +521,9 @@ fn invalid_unnamed_option() {
             path: "0".to_owned(),
             actual: "2".to_owned(),
             expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
@@ -463,6 +556,9 @@ fn invalid_unnamed_vec() {
             path: "0".to_owned(),
             actual: "0".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 
@@ -478,6 +574,9 @@ fn invalid_unnamed_vec() {
             path: "0".to_owned(),
             actual: "4".to_owned(),
             expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
@@ -521,6 +620,9 @@ fn invalid_enum() {
             path: "A.one".to_owned(),
             actual: "0".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 
@@ -536,6 +638,9 @@ fn invalid_enum() {
             path: "B._0".to_owned(),
             actual: "0".to_owned(),
             expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
@@ -572,6 +677,3594 @@ fn invalid_nested() {
             path: "o.0".to_owned(),
             actual: "2".to_owned(),
             expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct MapProperties {
+    #[validate(min_properties = 1, max_properties = 2)]
+    map: HashMap<String, i32>,
+}
+
+#[test]
+fn valid_map_properties() {
+    let result = MapProperties {
+        map: HashMap::from([("a".to_owned(), 1)]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = MapProperties {
+        map: HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_map_properties() {
+    let result = MapProperties {
+        map: HashMap::new(),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MinProperties,
+            path: "map".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = MapProperties {
+        map: HashMap::from([
+            ("a".to_owned(), 1),
+            ("b".to_owned(), 2),
+            ("c".to_owned(), 3),
+        ]),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MaxProperties,
+            path: "map".to_owned(),
+            actual: "3".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Deserialize, ToSchema, Validatable)]
+struct SerdeSkipped {
+    #[schema(minimum = 1)]
+    pub value: i32,
+    #[serde(skip)]
+    #[schema(minimum = 1)]
+    pub skipped: i32,
+    #[serde(skip_deserializing)]
+    #[schema(minimum = 1)]
+    pub skipped_deserializing: i32,
+    #[serde(skip)]
+    #[schema(minimum = 1)]
+    #[validate(skip = false)]
+    pub forced: i32,
+}
+
+#[test]
+fn serde_skipped_fields() {
+    let result = SerdeSkipped {
+        value: 1,
+        skipped: 0,
+        skipped_deserializing: 0,
+        forced: 1,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = SerdeSkipped {
+        value: 0,
+        skipped: 0,
+        skipped_deserializing: 0,
+        forced: 0,
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "value".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "forced".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+struct Name(String);
+
+impl_transparent_validatable!(Name => String);
+
+struct Age(i32);
+
+impl_transparent_validatable!(Age => i32);
+
+#[test]
+fn transparent_wrapper() {
+    let result = Name("name".to_owned()).validate();
+
+    assert!(result.is_ok());
+
+    let result = Age(0).validate();
+
+    assert!(result.is_ok());
+
+    let result = Age(1).validate_with(&TransparentValidator::new(MinimumValidator::new(1)));
+
+    assert!(result.is_ok());
+
+    let result = Age(0).validate_with(&TransparentValidator::new(MinimumValidator::new(1)));
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct ExplicitLengths {
+    #[validate(char_length(min = 2, max = 3), byte_length(min = 3, max = 4))]
+    pub s: String,
+}
+
+#[test]
+fn valid_explicit_lengths() {
+    let result = ExplicitLengths {
+        s: "abc".to_owned(),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = ExplicitLengths {
+        s: "äb".to_owned()
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_explicit_lengths() {
+    let result = ExplicitLengths { s: "a".to_owned() }.validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MinLength,
+            path: "s".to_owned(),
+            actual: "1".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::MinByteLength,
+            path: "s".to_owned(),
+            actual: "1".to_owned(),
+            expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = ExplicitLengths {
+        s: "äöü".to_owned(),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MaxByteLength,
+            path: "s".to_owned(),
+            actual: "6".to_owned(),
+            expected: "4".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[cfg(feature = "time")]
+#[derive(Validatable)]
+struct TimeFields {
+    #[validate(minimum = "2023-01-01T00:00:00Z")]
+    pub date_time: time::OffsetDateTime,
+    #[validate(maximum = "2023-12-31")]
+    pub date: Option<time::Date>,
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn valid_time_fields() {
+    let result = TimeFields {
+        date_time: time::macros::datetime!(2023-01-01 00:00 UTC),
+        date: Some(time::macros::date!(2023 - 12 - 31)),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = TimeFields {
+        date_time: time::macros::datetime!(2023-06-01 12:00 +02:00),
+        date: None,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[cfg(feature = "time")]
+#[derive(Validatable)]
+struct OffsetTimeFields {
+    #[validate(maximum = "2024-02-29T23:59:59.5+01:00")]
+    pub date_time: time::OffsetDateTime,
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn offset_time_fields() {
+    let result = OffsetTimeFields {
+        date_time: time::macros::datetime!(2024-02-29 22:59:59.5 UTC),
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = OffsetTimeFields {
+        date_time: time::macros::datetime!(2024-02-29 22:59:59.6 UTC),
+    }
+    .validate();
+    assert_eq!(
+        result.unwrap_err()[0].expected,
+        "2024-02-29 23:59:59.5 +01:00:00"
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn invalid_time_fields() {
+    let result = TimeFields {
+        date_time: time::macros::datetime!(2022-12-31 23:59:59 UTC),
+        date: Some(time::macros::date!(2024 - 01 - 01)),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "date_time".to_owned(),
+            actual: "2022-12-31 23:59:59.0 +00:00:00".to_owned(),
+            expected: "2023-01-01 0:00:00.0 +00:00:00".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "date".to_owned(),
+            actual: "2024-01-01".to_owned(),
+            expected: "2023-12-31".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct EnumItems {
+    items: Vec<Enum>,
+}
+
+#[test]
+fn invalid_enum_items() {
+    let result = vec![Enum::C, Enum::B(1), Enum::A { one: 0 }].validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "[2].A.one".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: Some(2),
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = EnumItems {
+        items: vec![Enum::C, Enum::A { one: 1 }, Enum::B(0)],
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "items[2].B._0".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: Some(2),
+            custom_message: None,
+            severity: Severity::Error,
         }
     );
 }
+
+#[derive(ToSchema, Validatable)]
+struct AllowedKeys {
+    #[validate(allowed_keys("a", "b"))]
+    map: HashMap<String, i32>,
+}
+
+#[test]
+fn valid_allowed_keys() {
+    let result = AllowedKeys {
+        map: HashMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_allowed_keys() {
+    let result = AllowedKeys {
+        map: HashMap::from([("a".to_owned(), 1), ("c".to_owned(), 3)]),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert!(matches!(
+        error[0].category,
+        ValidationErrorCategory::Other {
+            tag: "allowed_keys",
+            ..
+        }
+    ));
+    assert_eq!(error[0].path, "map[\"c\"]");
+    assert_eq!(error[0].actual, "c");
+    assert_eq!(error[0].expected, "a, b");
+    assert_eq!(
+        error[0].to_string(),
+        "map[\"c\"]: Must be one of the keys a, b but is c"
+    );
+}
+
+#[test]
+fn is_valid() {
+    assert!(UnnamedOption(Some(3)).is_valid());
+    assert!(!UnnamedOption(Some(2)).is_valid());
+    assert!(Nested {
+        o: UnnamedOption(None)
+    }
+    .is_valid());
+    assert!(!Nested {
+        o: UnnamedOption(Some(2))
+    }
+    .is_valid());
+}
+
+#[derive(ToSchema, Validatable)]
+struct Cells {
+    ref_cell: RefCell<UnnamedOption>,
+    cell: Cell<i32>,
+}
+
+#[test]
+fn valid_cells() {
+    let result = Cells {
+        ref_cell: RefCell::new(UnnamedOption(Some(3))),
+        cell: Cell::new(0),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = Cell::new(1).validate_with(&CellValidator::new(MinimumValidator::new(1)));
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_cells() {
+    let cells = Cells {
+        ref_cell: RefCell::new(UnnamedOption(Some(2))),
+        cell: Cell::new(0),
+    };
+    let result = cells.validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "ref_cell.0".to_owned(),
+            actual: "2".to_owned(),
+            expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let borrowed = cells.ref_cell.borrow_mut();
+    let result = cells.validate();
+    drop(borrowed);
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert!(matches!(
+        error[0].category,
+        ValidationErrorCategory::Other {
+            tag: "borrowed",
+            ..
+        }
+    ));
+    assert_eq!(
+        error[0].to_string(),
+        "ref_cell: Must not be mutably borrowed"
+    );
+
+    let result = Cell::new(0).validate_with(&CellValidator::new(MinimumValidator::new(1)));
+
+    assert!(result.is_err());
+}
+
+#[derive(ToSchema, Validatable)]
+struct Bytes {
+    #[validate(bytes(min_len = 1, max_len = 4))]
+    data: Vec<u8>,
+}
+
+#[test]
+fn valid_bytes() {
+    let result = Bytes { data: vec![0] }.validate();
+
+    assert!(result.is_ok());
+
+    let result = Bytes {
+        data: vec![0, 1, 2, 255],
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_bytes() {
+    let result = Bytes { data: vec![] }.validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MinByteLength,
+            path: "data".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = Bytes {
+        data: vec![0, 1, 2, 3, 4],
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MaxByteLength,
+            path: "data".to_owned(),
+            actual: "5".to_owned(),
+            expected: "4".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[allow(dead_code)]
+#[derive(ToSchema, Validatable)]
+struct Primitives {
+    pub flag: bool,
+    pub number: u64,
+    pub float: f64,
+    pub character: char,
+    pub text: String,
+    #[schema(maximum = 1)]
+    pub bounded: i32,
+}
+
+#[test]
+fn primitives() {
+    let result = Primitives {
+        flag: true,
+        number: 1,
+        float: 1.0,
+        character: 'a',
+        text: "a".to_owned(),
+        bounded: 1,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = Primitives {
+        flag: true,
+        number: 1,
+        float: 1.0,
+        character: 'a',
+        text: "a".to_owned(),
+        bounded: 2,
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "bounded".to_owned(),
+            actual: "2".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+mod model {
+    use utoipa_validate::Validatable;
+
+    #[derive(Validatable)]
+    pub struct String {
+        #[validate(minimum = 1)]
+        pub value: i32,
+    }
+}
+
+#[derive(Validatable)]
+struct ShadowedPrimitives {
+    text: model::String,
+    std_text: std::string::String,
+}
+
+#[test]
+fn shadowed_primitives() {
+    let result = ShadowedPrimitives {
+        text: model::String { value: 0 },
+        std_text: "a".to_owned(),
+    }
+    .validate();
+
+    assert_eq!(result.unwrap_err()[0].path, "text.value");
+}
+
+#[derive(ToSchema, Validatable)]
+struct Items {
+    #[schema(max_items = 2)]
+    items: Vec<UnnamedOption>,
+}
+
+#[test]
+fn invalid_items_index() {
+    let result = Items {
+        items: vec![
+            UnnamedOption(Some(3)),
+            UnnamedOption(Some(2)),
+            UnnamedOption(None),
+        ],
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "items[1].0".to_owned(),
+            actual: "2".to_owned(),
+            expected: "3".to_owned(),
+            index: Some(1),
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::MaxItems,
+            path: "items".to_owned(),
+            actual: "3".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+fn even(value: &i32, path: &ValidationPath, errors: &mut dyn ErrorSink) {
+    if value % 2 != 0 {
+        errors.push(ValidationError {
+            category: ValidationErrorCategory::Other {
+                tag: "even",
+                display: |error, f| write!(f, "{}: Must be even", error.path),
+                detail: None,
+            },
+            path: path.to_string(),
+            index: path.index(),
+            actual: value.to_string(),
+            expected: "even".to_owned(),
+            custom_message: None,
+            severity: Severity::Error,
+        });
+    }
+}
+
+#[derive(ToSchema, Validatable)]
+struct CustomValidators {
+    #[validate(with = even)]
+    pub value: i32,
+    #[validate(with = "even")]
+    pub option: Option<i32>,
+}
+
+#[derive(ToSchema, Validatable)]
+struct OrderedCustomValidators {
+    #[schema(maximum = 10)]
+    #[validate(with = even)]
+    #[schema(minimum = 5)]
+    pub value: i32,
+}
+
+#[test]
+fn custom_validator_order() {
+    let result = OrderedCustomValidators { value: 13 }.validate();
+    let categories = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.category.tag())
+        .collect::<Vec<_>>();
+    assert_eq!(categories, vec!["maximum", "even"]);
+
+    let result = OrderedCustomValidators { value: 3 }.validate();
+    let categories = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.category.tag())
+        .collect::<Vec<_>>();
+    assert_eq!(categories, vec!["even", "minimum"]);
+}
+
+#[test]
+fn valid_custom_validators() {
+    let result = CustomValidators {
+        value: 2,
+        option: None,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = CustomValidators {
+        value: 0,
+        option: Some(4),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_custom_validators() {
+    let result = CustomValidators {
+        value: 1,
+        option: Some(3),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(error[0].path, "value");
+    assert_eq!(error[0].to_string(), "value: Must be even");
+    assert_eq!(error[1].path, "option");
+    assert_eq!(error[1].actual, "3");
+}
+
+#[test]
+fn debug_validate_valid() {
+    debug_validate!(UnnamedOption(Some(3)));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "Validation failed: 0: Must be greater than or equal to 3 but is 2")]
+fn debug_validate_invalid() {
+    debug_validate!(UnnamedOption(Some(2)));
+}
+
+#[derive(ToSchema, Validatable)]
+struct Urls {
+    #[validate(url)]
+    pub any: String,
+    #[validate(url(schemes("https")))]
+    pub secure: Option<String>,
+}
+
+#[test]
+fn valid_urls() {
+    let result = Urls {
+        any: "http://example.com".to_owned(),
+        secure: Some("HTTPS://example.com/path?query#fragment".to_owned()),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = Urls {
+        any: "ftp://user@example.com:21".to_owned(),
+        secure: None,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_urls() {
+    let result = Urls {
+        any: "example.com".to_owned(),
+        secure: Some("http://x".to_owned()),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Format { format: "url" },
+            path: "any".to_owned(),
+            actual: "example.com".to_owned(),
+            expected: "url".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::UrlScheme,
+            path: "secure".to_owned(),
+            actual: "http".to_owned(),
+            expected: "https".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = Urls {
+        any: "http:// example.com".to_owned(),
+        secure: Some("https://".to_owned()),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0].category,
+        ValidationErrorCategory::Format { format: "url" }
+    );
+    assert_eq!(
+        error[1].category,
+        ValidationErrorCategory::Format { format: "url" }
+    );
+}
+
+#[derive(Validatable)]
+struct BorrowedUrls<'a> {
+    #[validate(url)]
+    pub any: &'a str,
+    #[validate(url(schemes("https")))]
+    pub secure: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn borrowed_urls() {
+    let result = BorrowedUrls {
+        any: "http://example.com",
+        secure: Some(Cow::Borrowed("https://example.com")),
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = BorrowedUrls {
+        any: "example.com",
+        secure: Some(Cow::Borrowed("http://example.com")),
+    }
+    .validate();
+    let categories = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.category)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        vec![
+            ValidationErrorCategory::Format { format: "url" },
+            ValidationErrorCategory::UrlScheme
+        ]
+    );
+}
+
+const INTEGER_STEP: u32 = 5;
+const FLOAT_STEP: f64 = 0.25;
+
+#[derive(ToSchema, Validatable)]
+struct ConstMultipleOf {
+    #[validate(multiple_of = INTEGER_STEP)]
+    pub integer: u32,
+    #[validate(multiple_of = FLOAT_STEP)]
+    pub float: f64,
+}
+
+#[test]
+fn valid_const_multiple_of() {
+    let result = ConstMultipleOf {
+        integer: 10,
+        float: 1.75,
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_const_multiple_of() {
+    let result = ConstMultipleOf {
+        integer: 11,
+        float: 1.7,
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MultipleOf,
+            path: "integer".to_owned(),
+            actual: "11".to_owned(),
+            expected: "5".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::MultipleOf,
+            path: "float".to_owned(),
+            actual: "1.7".to_owned(),
+            expected: "0.25".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+const MAX_TAGS: usize = 2;
+const MIN_TAGS: usize = 1;
+
+#[derive(ToSchema, Validatable)]
+struct ConstItems {
+    #[validate(min_items = MIN_TAGS, max_items = MAX_TAGS)]
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn invalid_const_items() {
+    let result = ConstItems {
+        tags: vec!["a".to_owned(), "b".to_owned()],
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = ConstItems {
+        tags: vec!["a".to_owned(), "b".to_owned(), "c".to_owned()],
+    }
+    .validate();
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::MaxItems,
+            path: "tags".to_owned(),
+            actual: "3".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+
+    let result = ConstItems { tags: vec![] }.validate();
+    assert_eq!(
+        result.unwrap_err()[0].category,
+        ValidationErrorCategory::MinItems
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct MaxErrors {
+    #[validate(max_errors = 2)]
+    items: Vec<UnnamedOption>,
+    #[validate(max_errors = 1)]
+    #[schema(minimum = 2, multiple_of = 2)]
+    value: i32,
+}
+
+#[test]
+fn invalid_max_errors() {
+    let result = MaxErrors {
+        items: vec![
+            UnnamedOption(Some(0)),
+            UnnamedOption(Some(1)),
+            UnnamedOption(Some(2)),
+        ],
+        value: 1,
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 3);
+    assert_eq!(error[0].path, "items[0].0");
+    assert_eq!(error[1].path, "items[1].0");
+    assert_eq!(error[2].path, "value");
+    assert_eq!(error[2].category, ValidationErrorCategory::Minimum);
+}
+
+#[derive(ToSchema, Validatable)]
+struct MapEntries {
+    #[validate(key(max_length = 3), value(maximum = 100))]
+    map: HashMap<String, i32>,
+}
+
+#[test]
+fn valid_map_entries() {
+    let result = MapEntries {
+        map: HashMap::from([("abc".to_owned(), 100)]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_map_entries() {
+    let result = MapEntries {
+        map: HashMap::from([("abcd".to_owned(), 1), ("a".to_owned(), 101)]),
+    }
+    .validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 2);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::MaxLength,
+            path: "map[\"abcd\"]".to_owned(),
+            actual: "4".to_owned(),
+            expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        error[1],
+        ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "map[\"a\"]".to_owned(),
+            actual: "101".to_owned(),
+            expected: "100".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[test]
+fn invalid_single_tuple() {
+    let result = (UnnamedOption(Some(3)),).validate();
+
+    assert!(result.is_ok());
+
+    let result = (UnnamedOption(Some(2)),).validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "[0].0".to_owned(),
+            actual: "2".to_owned(),
+            expected: "3".to_owned(),
+            index: Some(0),
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct TupleFields {
+    pair: (i32, UnnamedOption),
+    triple: (UnnamedOption, String, Vec<UnnamedOption>),
+}
+
+#[test]
+fn invalid_tuple_fields() {
+    let result = TupleFields {
+        pair: (1, UnnamedOption(Some(3))),
+        triple: (UnnamedOption(None), "".to_owned(), vec![]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let value = TupleFields {
+        pair: (1, UnnamedOption(Some(2))),
+        triple: (
+            UnnamedOption(Some(1)),
+            "".to_owned(),
+            vec![UnnamedOption(Some(3)), UnnamedOption(Some(0))],
+        ),
+    };
+
+    let paths = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["pair[1].0", "triple[0].0", "triple[2][1].0"]);
+    assert_eq!(value.validate_first().unwrap_err().path, "pair[1].0");
+}
+
+#[cfg(feature = "regex")]
+#[derive(ToSchema, Validatable)]
+struct Normalized {
+    #[validate(trim, lowercase)]
+    #[schema(pattern = "^[a-z]+$")]
+    pub name: String,
+    #[validate(trim)]
+    pub option: Option<String>,
+}
+
+#[allow(dead_code)]
+#[derive(ToSchema, Validatable)]
+enum NormalizedEnum {
+    A(#[validate(lowercase)] String),
+    B,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn normalize_and_validate() {
+    let mut value = Normalized {
+        name: "  AB  ".to_owned(),
+        option: Some(" c ".to_owned()),
+    };
+
+    assert!(value.validate().is_err());
+
+    let result = value.normalize_and_validate();
+
+    assert!(result.is_ok());
+    assert_eq!(value.name, "ab");
+    assert_eq!(value.option, Some("c".to_owned()));
+
+    let mut value = NormalizedEnum::A("AB".to_owned());
+    value.normalize();
+
+    assert!(matches!(value, NormalizedEnum::A(s) if s == "ab"));
+}
+
+#[derive(ToSchema, Validatable)]
+struct NestedFirst {
+    nested: Nested,
+    #[schema(maximum = 5)]
+    top: i32,
+}
+
+#[test]
+fn validate_breadth_first() {
+    let value = NestedFirst {
+        nested: Nested {
+            o: UnnamedOption(Some(2)),
+        },
+        top: 6,
+    };
+
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors[0].path, "nested.o.0");
+    assert_eq!(errors[1].path, "top");
+
+    let errors = value.validate_breadth_first().unwrap_err();
+    assert_eq!(errors[0].path, "top");
+    assert_eq!(errors[1].path, "nested.o.0");
+}
+
+#[derive(ToSchema, Validatable)]
+struct MapEntry {
+    #[schema(minimum = 1)]
+    field: i32,
+}
+
+#[test]
+fn one_based_map_keys() {
+    let value = HashMap::from([(
+        "a.b[0]".to_owned(),
+        vec![MapEntry { field: 1 }, MapEntry { field: 0 }],
+    )]);
+
+    let errors = value.validate().unwrap_err();
+
+    assert_eq!(errors[0].path, "[\"a.b[0]\"][1].field");
+    assert_eq!(
+        errors[0].one_based().to_string(),
+        "[\"a.b[0]\"][2].field: Must be greater than or equal to 1 but is 0"
+    );
+}
+
+#[test]
+fn map_key_depth() {
+    let value = vec![HashMap::from([
+        ("a.b[0]".to_owned(), MapEntry { field: 0 }),
+        ("c".to_owned(), MapEntry { field: 0 }),
+    ])];
+
+    let errors = value.validate().unwrap_err();
+
+    assert!(errors.iter().all(|error| error.depth() == 3));
+}
+
+#[test]
+fn invalid_vec_of_maps() {
+    let value = vec![
+        HashMap::from([("k".to_owned(), MapEntry { field: 1 })]),
+        HashMap::from([("k".to_owned(), MapEntry { field: 0 })]),
+    ];
+
+    let result = value.validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "[1][\"k\"].field".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: Some(1),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[allow(dead_code)]
+#[derive(ToSchema, Validatable)]
+struct Described {
+    #[schema(minimum = 1)]
+    id: i32,
+    #[schema(max_length = 10, pattern = "^[a-z]*$")]
+    name: String,
+    comment: String,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn constraints() {
+    assert_eq!(
+        DescribedValidator::constraints(),
+        vec![
+            FieldConstraint {
+                field: "id",
+                constraints: vec![("minimum", "1")],
+            },
+            FieldConstraint {
+                field: "name",
+                constraints: vec![("max_length", "10"), ("pattern", "^[a-z]*$")],
+            },
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct NestedOptionItems(#[schema(minimum = 1, max_items = 2)] Option<Vec<Option<i32>>>);
+
+#[test]
+fn valid_nested_option_items() {
+    assert!(NestedOptionItems(None).validate().is_ok());
+    assert!(NestedOptionItems(Some(vec![])).validate().is_ok());
+    assert!(NestedOptionItems(Some(vec![None, Some(1)]))
+        .validate()
+        .is_ok());
+}
+
+#[test]
+fn invalid_nested_option_items() {
+    let result = NestedOptionItems(Some(vec![None, Some(0)])).validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "0[1]".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: Some(1),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+
+    let result = NestedOptionItems(Some(vec![None, None, Some(1)])).validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::MaxItems,
+            path: "0".to_owned(),
+            actual: "3".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn string_length_counts_characters() {
+    let result = StringFields {
+        s: "héllo".to_owned(),
+        hex: "ab".to_owned(),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+
+    let result = StringFields {
+        s: "🦀🦀🦀🦀🦀🦀".to_owned(),
+        hex: "ab".to_owned(),
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::MaxLength,
+            path: "s".to_owned(),
+            actual: "6".to_owned(),
+            expected: "5".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[derive(Validatable)]
+struct Fixed<const N: usize> {
+    data: [UnnamedOption; N],
+}
+
+#[test]
+fn invalid_const_generic() {
+    let result = Fixed {
+        data: [UnnamedOption(Some(3)), UnnamedOption(Some(2))],
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "data[1].0".to_owned(),
+            actual: "2".to_owned(),
+            expected: "3".to_owned(),
+            index: Some(1),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+
+    assert!(Fixed::<0> { data: [] }.validate().is_ok());
+}
+
+#[cfg(feature = "regex")]
+fn runtime_pattern() -> String {
+    ["^[a-z", "+$"].concat()
+}
+
+#[cfg(feature = "regex")]
+#[derive(Validatable)]
+struct FalliblePattern {
+    #[validate(pattern_fallible = runtime_pattern())]
+    value: String,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn invalid_fallible_pattern() {
+    let result = FalliblePattern {
+        value: "abc".to_owned(),
+    }
+    .validate();
+
+    let errors = result.unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].to_string(),
+        "value: The pattern ^[a-z+$ is invalid"
+    );
+}
+
+#[cfg(feature = "regex")]
+#[derive(Validatable)]
+struct BorrowedFalliblePattern<'a> {
+    #[validate(pattern_fallible = runtime_pattern())]
+    value: &'a str,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn invalid_borrowed_fallible_pattern() {
+    let errors = BorrowedFalliblePattern { value: "abc" }
+        .validate()
+        .unwrap_err();
+    assert_eq!(errors[0].category.tag(), "invalid_pattern");
+}
+
+#[cfg(feature = "regex")]
+static PATTERN_EVALUATIONS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+#[cfg(feature = "regex")]
+fn counted_pattern() -> &'static str {
+    PATTERN_EVALUATIONS.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    "^[a-z]+$"
+}
+
+#[cfg(feature = "regex")]
+#[derive(Validatable)]
+#[validate(changed)]
+struct CountedPattern {
+    #[validate(pattern = counted_pattern())]
+    value: String,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_compiled_once() {
+    let evaluations = || PATTERN_EVALUATIONS.load(std::sync::atomic::Ordering::SeqCst);
+    let previous = CountedPattern {
+        value: "xyz".to_owned(),
+    };
+
+    for value in ["abc", "ABC", "def"] {
+        let value = CountedPattern {
+            value: value.to_owned(),
+        };
+
+        let _ = value.validate();
+        let _ = value.is_valid();
+        let _ = value.validate_changed(&previous);
+    }
+
+    // All generated functions share the regex.
+    assert_eq!(evaluations(), 1);
+}
+
+#[test]
+fn validate_maps() {
+    assert!(HashMap::<String, MapEntry>::new().validate().is_ok());
+    assert!(BTreeMap::<String, MapEntry>::new().validate().is_ok());
+
+    let result =
+        BTreeMap::from([(1, MapEntry { field: 1 }), (2, MapEntry { field: 0 })]).validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "[\"2\"].field".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Sets {
+    #[schema(min_items = 1, max_items = 2)]
+    hash_set: HashSet<i32>,
+    #[schema(max_items = 1)]
+    b_tree_set: BTreeSet<UnnamedOption>,
+}
+
+#[test]
+fn valid_sets() {
+    let result = Sets {
+        hash_set: HashSet::from([1, 2]),
+        b_tree_set: BTreeSet::from([UnnamedOption(Some(3))]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_sets() {
+    let result = Sets {
+        hash_set: HashSet::new(),
+        b_tree_set: BTreeSet::from([UnnamedOption(Some(2)), UnnamedOption(Some(3))]),
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![
+            ValidationError {
+                category: ValidationErrorCategory::MinItems,
+                path: "hash_set".to_owned(),
+                actual: "0".to_owned(),
+                expected: "1".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::Minimum,
+                path: "b_tree_set[0].0".to_owned(),
+                actual: "2".to_owned(),
+                expected: "3".to_owned(),
+                index: Some(0),
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::MaxItems,
+                path: "b_tree_set".to_owned(),
+                actual: "2".to_owned(),
+                expected: "1".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+        ]
+    );
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Version(u32, u32);
+
+impl Display for Version {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.0, self.1)
+    }
+}
+
+impl Validatable for Version {
+    type DefaultValidator = AlwaysValidValidator;
+}
+
+#[derive(Validatable)]
+struct Dependency {
+    #[validate(minimum = Version(1, 2), maximum = Version(2, 0))]
+    version: Version,
+}
+
+#[test]
+fn custom_ordered_bounds() {
+    assert!(Dependency {
+        version: Version(1, 5)
+    }
+    .validate()
+    .is_ok());
+
+    let result = Dependency {
+        version: Version(1, 1),
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "version".to_owned(),
+            actual: "1.1".to_owned(),
+            expected: "1.2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[derive(Validatable)]
+struct UniqueItems {
+    #[validate(unique_items)]
+    ids: Vec<i32>,
+    #[validate(unique_items = true)]
+    names: Option<Vec<String>>,
+}
+
+#[test]
+fn valid_unique_items() {
+    let result = UniqueItems {
+        ids: vec![1, 2, 3],
+        names: Some(vec!["a".to_owned(), "b".to_owned()]),
+    }
+    .validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_unique_items() {
+    let result = UniqueItems {
+        ids: vec![1, 2, 1, 2],
+        names: Some(vec!["a".to_owned(), "a".to_owned()]),
+    }
+    .validate();
+
+    let errors = result.unwrap_err();
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError {
+                category: ValidationErrorCategory::UniqueItems,
+                path: "ids".to_owned(),
+                actual: "2".to_owned(),
+                expected: "0".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::UniqueItems,
+                path: "names".to_owned(),
+                actual: "1".to_owned(),
+                expected: "0".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+        ]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "ids: Must have unique items but item 2 is equal to item 0"
+    );
+}
+
+#[derive(Validatable)]
+enum MixedVariant {
+    Variant(
+        #[validate(skip)] UnnamedOption,
+        #[validate(minimum = 1)] i32,
+    ),
+}
+
+#[test]
+fn invalid_mixed_variant() {
+    let result = MixedVariant::Variant(UnnamedOption(Some(0)), 0).validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "Variant._1".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Overridable {
+    #[schema(minimum = 1)]
+    first: i32,
+    #[schema(minimum = 1)]
+    second: i32,
+}
+
+#[test]
+fn override_field_validator() {
+    let value = Overridable {
+        first: 5,
+        second: 0,
+    };
+    let validator = OverridableValidator::default().with_first_override(MinimumValidator::new(10));
+
+    assert_eq!(
+        value.validate_with(&validator).unwrap_err(),
+        vec![
+            ValidationError {
+                category: ValidationErrorCategory::Minimum,
+                path: "first".to_owned(),
+                actual: "5".to_owned(),
+                expected: "10".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::Minimum,
+                path: "second".to_owned(),
+                actual: "0".to_owned(),
+                expected: "1".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+enum OverridableVariants {
+    Named {
+        #[schema(minimum = 1)]
+        value: i32,
+    },
+    Unnamed(#[schema(minimum = 1)] i32),
+}
+
+#[test]
+fn override_variant_field_validators() {
+    let validator = OverridableVariantsValidator::default()
+        .with_named_value_override(MinimumValidator::new(10))
+        .with_unnamed_0_override(MaximumValidator::new(-1));
+
+    assert_eq!(
+        OverridableVariants::Named { value: 5 }
+            .validate_with(&validator)
+            .unwrap_err()[0]
+            .path,
+        "Named.value"
+    );
+    assert_eq!(
+        OverridableVariants::Unnamed(5)
+            .validate_with(&validator)
+            .unwrap_err()[0]
+            .path,
+        "Unnamed._0"
+    );
+    assert!(OverridableVariants::Named { value: 0 }
+        .validate_with(&OverridableVariantsValidator::default())
+        .is_err());
+}
+
+#[derive(ToSchema, Validatable)]
+struct Coordinates {
+    #[schema(minimum = -1.0, maximum = 1.0)]
+    coords: [f64; 3],
+}
+
+#[test]
+fn invalid_array_items() {
+    assert!(Coordinates {
+        coords: [0.0, 1.0, -1.0]
+    }
+    .validate()
+    .is_ok());
+
+    let result = Coordinates {
+        coords: [0.0, 0.5, 1.5],
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "coords[2]".to_owned(),
+            actual: "1.5".to_owned(),
+            expected: "1".to_owned(),
+            index: Some(2),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Contact {
+    #[schema(format = "email")]
+    email: String,
+    #[validate(format = Email)]
+    backup_email: Option<String>,
+}
+
+#[test]
+fn valid_email() {
+    for email in ["a@b.de", "first.last+tag@mail.example.com", "x_y@a-b.org"] {
+        let result = Contact {
+            email: email.to_owned(),
+            backup_email: Some(email.to_owned()),
+        }
+        .validate();
+
+        assert!(result.is_ok(), "{}", email);
+    }
+}
+
+#[test]
+fn invalid_email() {
+    for email in [
+        "",
+        "a",
+        "@b.de",
+        "a@b",
+        "a@-b.de",
+        "a..b@c.de",
+        "a b@c.de",
+        "a@b..de",
+    ] {
+        let result = Contact {
+            email: "a@b.de".to_owned(),
+            backup_email: Some(email.to_owned()),
+        }
+        .validate();
+
+        assert_eq!(
+            result.unwrap_err(),
+            vec![ValidationError {
+                category: ValidationErrorCategory::Format { format: "email" },
+                path: "backup_email".to_owned(),
+                actual: email.to_owned(),
+                expected: "email".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            }]
+        );
+    }
+}
+
+#[derive(Validatable)]
+struct BorrowedContact<'a> {
+    #[validate(format = Email)]
+    email: &'a str,
+    #[validate(format = Email)]
+    backup_email: Option<Box<str>>,
+}
+
+#[test]
+fn borrowed_email() {
+    let result = BorrowedContact {
+        email: "a@b.de",
+        backup_email: Some("a@b".into()),
+    }
+    .validate();
+
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["backup_email"]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct PlatformIntegers {
+    #[schema(maximum = 10)]
+    size: usize,
+    #[schema(minimum = -10, maximum = 10)]
+    offset: isize,
+}
+
+#[test]
+fn platform_integers() {
+    assert!(PlatformIntegers {
+        size: 10,
+        offset: -10
+    }
+    .validate()
+    .is_ok());
+
+    let result = PlatformIntegers {
+        size: usize::MAX,
+        offset: isize::MIN,
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![
+            ValidationError {
+                category: ValidationErrorCategory::Maximum,
+                path: "size".to_owned(),
+                actual: usize::MAX.to_string(),
+                expected: "10".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::Minimum,
+                path: "offset".to_owned(),
+                actual: isize::MIN.to_string(),
+                expected: "-10".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct RequiredOption {
+    #[validate(required)]
+    #[schema(minimum = 1)]
+    value: Option<i32>,
+}
+
+#[test]
+fn required_option() {
+    assert!(RequiredOption { value: Some(1) }.validate().is_ok());
+
+    let errors = RequiredOption { value: None }.validate().unwrap_err();
+
+    assert_eq!(
+        errors,
+        vec![ValidationError {
+            category: ValidationErrorCategory::Required,
+            path: "value".to_owned(),
+            actual: "null".to_owned(),
+            expected: "a value".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+    assert_eq!(errors[0].to_string(), "value: Is required");
+    assert_eq!(
+        RequiredOption { value: Some(0) }.validate().unwrap_err()[0].category,
+        ValidationErrorCategory::Minimum
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Identifiers {
+    #[validate(format = Uuid)]
+    id: String,
+    #[schema(format = "uuid")]
+    parent_id: String,
+}
+
+#[test]
+fn valid_uuid() {
+    for uuid in [
+        "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "67E55044-10B1-426F-9247-BB680E5FE0C8",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8}",
+    ] {
+        let result = Identifiers {
+            id: uuid.to_owned(),
+            parent_id: uuid.to_owned(),
+        }
+        .validate();
+
+        assert!(result.is_ok(), "{}", uuid);
+    }
+}
+
+#[test]
+fn invalid_uuid() {
+    for uuid in [
+        "",
+        "67e55044-10b1-426f-9247-bb680e5fe0c",
+        "67e55044-10b1-426f-9247-bb680e5fe0c8a",
+        "67e55044-10b1-426f-9247bb680e5fe0c8",
+        "67e55044-10b1-426f-9247-bb680e5fe0cg",
+        "{67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        let result = Identifiers {
+            id: uuid.to_owned(),
+            parent_id: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_owned(),
+        }
+        .validate();
+
+        assert_eq!(
+            result.unwrap_err(),
+            vec![ValidationError {
+                category: ValidationErrorCategory::Format { format: "uuid" },
+                path: "id".to_owned(),
+                actual: uuid.to_owned(),
+                expected: "uuid".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            }]
+        );
+    }
+}
+
+#[derive(Validatable)]
+struct BorrowedIdentifiers<'a> {
+    #[validate(format = Uuid)]
+    id: Cow<'a, str>,
+    #[validate(format = Uuid)]
+    parent_id: &'a str,
+}
+
+#[test]
+fn borrowed_uuid() {
+    let result = BorrowedIdentifiers {
+        id: Cow::Borrowed("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+        parent_id: "67e55044-10b1-426f-9247",
+    }
+    .validate();
+
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["parent_id"]);
+}
+
+#[cfg(feature = "regex")]
+#[derive(Validatable)]
+struct SharedStrings {
+    #[validate(max_length = 3, pattern = "^[a-z]*$")]
+    name: Arc<str>,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn invalid_shared_string() {
+    assert!(SharedStrings { name: "abc".into() }.validate().is_ok());
+
+    let result = SharedStrings {
+        name: "abcd".into(),
+    }
+    .validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::MaxLength,
+            path: "name".to_owned(),
+            actual: "4".to_owned(),
+            expected: "3".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[derive(Validatable)]
+struct BoxedStrings {
+    #[validate(min_length = 1, max_length = 4, pattern = "^[a-z]*$")]
+    name: Box<str>,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn invalid_boxed_string() {
+    assert!(BoxedStrings { name: "abc".into() }.validate().is_ok());
+
+    let result = BoxedStrings { name: "aBc".into() }.validate();
+
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Pattern,
+            path: "name".to_owned(),
+            actual: "aBc".to_owned(),
+            expected: "^[a-z]*$".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+
+    let result = BoxedStrings { name: "".into() }.validate();
+    assert_eq!(
+        result.unwrap_err()[0].category,
+        ValidationErrorCategory::MinLength
+    );
+}
+
+#[derive(Deserialize, ToSchema, Validatable)]
+struct Renamed {
+    #[serde(rename = "userName")]
+    #[schema(min_length = 1)]
+    user_name: String,
+    #[serde(rename(serialize = "out", deserialize = "in"))]
+    #[schema(minimum = 1)]
+    value: i32,
+}
+
+#[test]
+fn invalid_renamed() {
+    let errors = Renamed {
+        user_name: String::new(),
+        value: 0,
+    }
+    .validate()
+    .unwrap_err();
+
+    let paths = errors
+        .iter()
+        .map(|error| error.path.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["userName", "in"]);
+}
+
+#[derive(Deserialize, ToSchema, Validatable)]
+#[serde(rename_all = "camelCase")]
+struct CamelCase {
+    #[schema(minimum = 1)]
+    first_value: i32,
+    #[serde(rename = "second")]
+    #[schema(minimum = 1)]
+    second_value: i32,
+}
+
+#[derive(Deserialize, Validatable)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+struct ScreamingSnakeCase {
+    #[validate(minimum = 1)]
+    first_value: i32,
+}
+
+#[derive(Deserialize, Validatable)]
+enum KebabCase {
+    #[serde(rename_all = "kebab-case")]
+    Variant {
+        #[validate(minimum = 1)]
+        first_value: i32,
+    },
+}
+
+#[derive(Deserialize, Validatable)]
+#[serde(rename_all = "PascalCase")]
+struct PascalCase {
+    #[validate(minimum = 1)]
+    first_value: i32,
+}
+
+#[derive(Deserialize, Validatable)]
+#[serde(rename_all = "snake_case")]
+enum SnakeCaseVariants {
+    FirstVariant {
+        #[validate(minimum = 1)]
+        value: i32,
+    },
+    #[serde(rename = "second")]
+    SecondVariant(#[validate(minimum = 1)] i32),
+}
+
+#[derive(Deserialize, Validatable)]
+#[serde(rename_all = "SCREAMING-KEBAB-CASE")]
+enum ScreamingKebabCaseVariants {
+    #[serde(rename_all = "camelCase")]
+    FirstVariant {
+        #[validate(minimum = 1)]
+        first_value: i32,
+    },
+}
+
+#[test]
+fn rename_all() {
+    let paths = |errors: Vec<ValidationError>| {
+        errors
+            .into_iter()
+            .map(|error| error.path)
+            .collect::<Vec<_>>()
+    };
+
+    let result = CamelCase {
+        first_value: 0,
+        second_value: 0,
+    }
+    .validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["firstValue", "second"]);
+
+    let result = ScreamingSnakeCase { first_value: 0 }.validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["FIRST_VALUE"]);
+
+    let result = KebabCase::Variant { first_value: 0 }.validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["Variant.first-value"]);
+
+    let result = PascalCase { first_value: 0 }.validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["FirstValue"]);
+
+    let result = SnakeCaseVariants::FirstVariant { value: 0 }.validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["first_variant.value"]);
+
+    let result = SnakeCaseVariants::SecondVariant(0).validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["second._0"]);
+
+    let result = ScreamingKebabCaseVariants::FirstVariant { first_value: 0 }.validate();
+    assert_eq!(paths(result.unwrap_err()), vec!["FIRST-VARIANT.firstValue"]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct Documented {
+    #[schema(inline, nullable)]
+    nested: Nested,
+    #[schema(rename = "val", write_only, deprecated, minimum = 1)]
+    value: i32,
+}
+
+#[test]
+fn invalid_documented() {
+    let result = Documented {
+        nested: Nested {
+            o: UnnamedOption(Some(2)),
+        },
+        value: 0,
+    }
+    .validate();
+
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["nested.o.0", "value"]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct SchemaTyped {
+    #[schema(value_type = Option<String>, example = json!("abc"), min_length = 1)]
+    name: Option<String>,
+    #[schema(value_type = i64, example = json!(5), minimum = 1, maximum = 10)]
+    value: i32,
+}
+
+#[test]
+fn invalid_schema_typed() {
+    let result = SchemaTyped {
+        name: Some("".to_owned()),
+        value: 11,
+    }
+    .validate();
+
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["name", "value"]);
+
+    let result = SchemaTyped {
+        name: None,
+        value: 1,
+    }
+    .validate();
+    assert!(result.is_ok());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn validate_err() {
+    fn check(value: &StringFields) -> Result<(), Box<dyn std::error::Error>> {
+        value.validate_err()?;
+        Ok(())
+    }
+
+    let value = StringFields {
+        s: "".to_owned(),
+        hex: "x".to_owned(),
+    };
+
+    assert_eq!(
+        check(&value).unwrap_err().to_string(),
+        "s: Must have at least 1 characters but has 0\nhex: Must match the regular expression ^[0-9a-f]+$ but is x"
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct FailFast {
+    items: Vec<Nested>,
+    #[schema(minimum = 1)]
+    value: i32,
+}
+
+#[test]
+fn validate_first() {
+    let value = FailFast {
+        items: vec![
+            Nested {
+                o: UnnamedOption(Some(1)),
+            },
+            Nested {
+                o: UnnamedOption(Some(2)),
+            },
+            Nested {
+                o: UnnamedOption(Some(3)),
+            },
+        ],
+        value: 0,
+    };
+
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(value.validate_first().unwrap_err(), errors[0]);
+    assert_eq!(errors[0].path, "items[0].o.0");
+    assert!(!value.is_valid());
+
+    let value = FailFast {
+        items: vec![],
+        value: 0,
+    };
+    assert_eq!(value.validate_first().unwrap_err().path, "value");
+
+    let value = FailFast {
+        items: vec![],
+        value: 1,
+    };
+    assert_eq!(value.validate_first(), Ok(()));
+    assert!(value.is_valid());
+}
+
+#[test]
+fn validate_first_override() {
+    let value = Overridable {
+        first: 5,
+        second: 0,
+    };
+    let validator = OverridableValidator::default().with_first_override(MinimumValidator::new(10));
+
+    let result = Validator::validate_first(&validator, &ValidationPath::Root, &value);
+    assert_eq!(
+        result,
+        ControlFlow::Break(ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "first".to_owned(),
+            actual: "5".to_owned(),
+            expected: "10".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        })
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn validate_owned() {
+    let value = StringFields {
+        s: "abc".to_owned(),
+        hex: "ff".to_owned(),
+    }
+    .validate_owned()
+    .unwrap();
+    assert_eq!(value.s, "abc");
+
+    let result = StringFields {
+        s: "".to_owned(),
+        hex: "ff".to_owned(),
+    }
+    .validate_owned();
+    assert_eq!(result.err().unwrap().len(), 1);
+}
+
+#[derive(ToSchema, Validatable)]
+struct Warnings {
+    #[schema(min_length = 1)]
+    name: String,
+    #[validate(warning, max_length = 3)]
+    nickname: String,
+}
+
+#[test]
+fn validate_owned_with_warnings() {
+    let (value, warnings) = Warnings {
+        name: "a".to_owned(),
+        nickname: "abc".to_owned(),
+    }
+    .validate_owned_with_warnings()
+    .unwrap();
+    assert_eq!(value.nickname, "abc");
+    assert!(warnings.is_empty());
+
+    let (value, warnings) = Warnings {
+        name: "a".to_owned(),
+        nickname: "abcd".to_owned(),
+    }
+    .validate_owned_with_warnings()
+    .unwrap();
+    assert_eq!(value.nickname, "abcd");
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].path, "nickname");
+    assert_eq!(warnings[0].severity, Severity::Warning);
+
+    let errors = Warnings {
+        name: "".to_owned(),
+        nickname: "abcd".to_owned(),
+    }
+    .validate_owned_with_warnings()
+    .err()
+    .unwrap();
+    let severities = errors
+        .iter()
+        .map(|error| error.severity)
+        .collect::<Vec<_>>();
+    assert_eq!(severities, vec![Severity::Error, Severity::Warning]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct Pointers {
+    boxed: Box<UnnamedOption>,
+    shared: Rc<UnnamedOption>,
+    atomic: Arc<UnnamedOption>,
+}
+
+#[test]
+fn invalid_pointers() {
+    let unboxed = Nested {
+        o: UnnamedOption(Some(2)),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(unboxed[0].path, "o.0");
+
+    let result = Pointers {
+        boxed: Box::new(UnnamedOption(Some(2))),
+        shared: Rc::new(UnnamedOption(Some(1))),
+        atomic: Arc::new(UnnamedOption(Some(3))),
+    }
+    .validate();
+
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["boxed.0", "shared.0"]);
+}
+
+#[cfg(feature = "regex")]
+#[derive(ToSchema, Validatable)]
+struct BorrowedStrings<'a> {
+    #[schema(max_length = 3)]
+    cow: Cow<'a, str>,
+    #[schema(min_length = 2, pattern = "^[a-z]+$")]
+    slice: &'a str,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn borrowed_strings() {
+    let value = BorrowedStrings {
+        cow: Cow::Borrowed("äöü"),
+        slice: "ab",
+    };
+    assert!(value.validate().is_ok());
+
+    let value = BorrowedStrings {
+        cow: Cow::Owned("äöüß".to_owned()),
+        slice: "A",
+    };
+    let errors = value.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| (error.path.as_str(), error.actual.as_str()))
+            .collect::<Vec<_>>(),
+        vec![("cow", "4"), ("slice", "1"), ("slice", "A")]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct PatchRequest {
+    #[schema(minimum = 1)]
+    value: Patch<i32>,
+}
+
+#[test]
+fn validate_patch() {
+    let result = PatchRequest {
+        value: Patch::Absent,
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = PatchRequest { value: Patch::Null }.validate();
+    assert!(result.is_ok());
+
+    let result = PatchRequest {
+        value: Patch::Present(1),
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = PatchRequest {
+        value: Patch::Present(0),
+    }
+    .validate();
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Minimum,
+            path: "value".to_owned(),
+            actual: "0".to_owned(),
+            expected: "1".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[test]
+fn patch_states() {
+    assert_eq!(Patch::<i32>::from(None), Patch::Absent);
+    assert_eq!(Patch::<i32>::from(Some(None)), Patch::Null);
+    assert_eq!(Patch::from(Some(Some(3))), Patch::Present(3));
+
+    assert!(Patch::<i32>::default().is_absent());
+    assert!(Patch::<i32>::Null.is_null());
+    assert_eq!(Patch::Present(3).as_present(), Some(&3));
+    assert_eq!(Patch::<i32>::Null.into_option(), Some(None));
+}
+
+#[derive(ToSchema, Validatable)]
+struct Constants {
+    #[validate(const = 42)]
+    integer: i32,
+    #[validate(const = 1.5)]
+    float: f64,
+    #[validate(const = "v1")]
+    string: String,
+    #[validate(const = 7)]
+    option: Option<u8>,
+}
+
+#[derive(ToSchema, Validatable)]
+struct ConstValue {
+    #[validate(const_value = 5)]
+    value: i64,
+}
+
+#[test]
+fn validate_const_value() {
+    assert!(ConstValue { value: 5 }.validate().is_ok());
+
+    let result = ConstValue { value: 4 }.validate();
+    assert_eq!(
+        result.unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Const,
+            path: "value".to_owned(),
+            actual: "4".to_owned(),
+            expected: "5".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[test]
+fn validate_const() {
+    let result = Constants {
+        integer: 42,
+        float: 1.5,
+        string: "v1".to_owned(),
+        option: None,
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = Constants {
+        integer: 41,
+        float: 2.0,
+        string: "v2".to_owned(),
+        option: Some(8),
+    }
+    .validate();
+    let messages = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "integer: Must be equal to 42 but is 41",
+            "float: Must be equal to 1.5 but is 2",
+            "string: Must be equal to v1 but is v2",
+            "option: Must be equal to 7 but is 8",
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct SortedMap {
+    #[validate(sorted)]
+    entries: HashMap<String, UnnamedOption>,
+}
+
+#[test]
+fn validate_sorted_map() {
+    let value = SortedMap {
+        entries: ["e", "b", "d", "a", "c"]
+            .into_iter()
+            .map(|key| (key.to_owned(), UnnamedOption(Some(1))))
+            .collect(),
+    };
+
+    let paths = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "entries[\"a\"].0",
+            "entries[\"b\"].0",
+            "entries[\"c\"].0",
+            "entries[\"d\"].0",
+            "entries[\"e\"].0"
+        ]
+    );
+    assert_eq!(value.validate_first().unwrap_err().path, "entries[\"a\"].0");
+}
+
+#[derive(ToSchema, Validatable)]
+struct Enumerations {
+    #[validate(enumeration = ["asc", "desc"])]
+    order: String,
+    #[validate(enumeration = [1, 2, 4])]
+    size: Option<u8>,
+}
+
+#[test]
+fn valid_enumerations() {
+    let result = Enumerations {
+        order: "desc".to_owned(),
+        size: Some(4),
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = Enumerations {
+        order: "asc".to_owned(),
+        size: None,
+    }
+    .validate();
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_enumerations() {
+    let result = Enumerations {
+        order: "up".to_owned(),
+        size: Some(3),
+    }
+    .validate();
+
+    let errors = result.unwrap_err();
+    assert_eq!(
+        errors[0],
+        ValidationError {
+            category: ValidationErrorCategory::Enumeration,
+            path: "order".to_owned(),
+            actual: "up".to_owned(),
+            expected: "asc, desc".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+    assert_eq!(
+        errors[1].to_string(),
+        "size: Must be one of 1, 2, 4 but is 3"
+    );
+}
+
+#[test]
+fn validate_visit() {
+    let value = FailFast {
+        items: vec![
+            Nested {
+                o: UnnamedOption(Some(1)),
+            },
+            Nested {
+                o: UnnamedOption(Some(2)),
+            },
+        ],
+        value: 0,
+    };
+
+    let mut categories = HashSet::new();
+    let mut count = 0;
+    value.validate_visit(&mut |error| {
+        count += 1;
+        categories.insert(error.category.tag());
+    });
+
+    assert_eq!(count, 3);
+    assert_eq!(categories, HashSet::from(["minimum"]));
+
+    let mut paths = Vec::new();
+    value.validate_visit(&mut |error| {
+        if paths.len() < 2 {
+            paths.push(error.path.clone());
+        }
+    });
+    assert_eq!(paths, vec!["items[0].o.0", "items[1].o.0"]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct FiniteFloats {
+    #[validate(finite)]
+    value: f64,
+    #[validate(finite)]
+    values: Vec<f32>,
+}
+
+#[test]
+fn validate_finite() {
+    let result = FiniteFloats {
+        value: 1.5,
+        values: vec![0.0, -2.0],
+    }
+    .validate();
+    assert!(result.is_ok());
+
+    let result = FiniteFloats {
+        value: f64::INFINITY,
+        values: vec![0.0, f32::NAN, f32::NEG_INFINITY],
+    }
+    .validate();
+    let messages = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "value: Must be a finite number but is inf",
+            "values[1]: Must be a finite number but is NaN",
+            "values[2]: Must be a finite number but is -inf",
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Labeled {
+    #[validate(label = "Email")]
+    #[schema(min_length = 5)]
+    email: String,
+    #[schema(minimum = 1)]
+    age: u8,
+}
+
+#[test]
+fn labeled_messages() {
+    assert_eq!(LabeledValidator::labels(), &[("email", "Email")]);
+
+    let errors = Labeled {
+        email: "a@b".to_owned(),
+        age: 0,
+    }
+    .validate()
+    .unwrap_err();
+
+    let labels = LabeledValidator::labels();
+    assert_eq!(
+        errors[0].labeled(labels).to_string(),
+        "Email: Must have at least 5 characters but has 3"
+    );
+    assert_eq!(errors[0].path, "email");
+    assert_eq!(
+        errors[1].labeled(labels).to_string(),
+        "age: Must be greater than or equal to 1 but is 0"
+    );
+}
+
+mod first {
+    use utoipa::ToSchema;
+    use utoipa_validate::Validatable;
+
+    #[derive(ToSchema, Validatable)]
+    pub struct Item {
+        #[schema(minimum = 1)]
+        pub value: i32,
+    }
+}
+
+mod second {
+    use utoipa::ToSchema;
+    use utoipa_validate::Validatable;
+
+    #[derive(ToSchema, Validatable)]
+    pub struct Item {
+        #[schema(max_length = 1)]
+        pub value: String,
+    }
+}
+
+#[test]
+fn reuse_validators_across_modules() {
+    let parent = ValidationPath::Field {
+        parent: &ValidationPath::Root,
+        name: "parent",
+    };
+    let mut errors = Vec::new();
+
+    first::ItemValidator::new().validate(&parent, &first::Item { value: 0 }, &mut errors);
+    second::ItemValidator::default().validate(
+        &parent,
+        &second::Item {
+            value: "ab".to_owned(),
+        },
+        &mut errors,
+    );
+
+    let messages = errors
+        .iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "parent.value: Must be greater than or equal to 1 but is 0",
+            "parent.value: Must have at most 1 characters but has 2",
+        ]
+    );
+}
+
+#[test]
+fn validate_all() {
+    let query = first::Item { value: 0 };
+    let body = Labeled {
+        email: "a@b".to_owned(),
+        age: 1,
+    };
+
+    let result = validate_all!("query" => &query, "body" => &body);
+    let paths = result
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["query.value", "body.email"]);
+
+    let query = first::Item { value: 1 };
+    let result: Result<(), Vec<ValidationError>> = validate_all!("query" => &query);
+    assert!(result.is_ok());
+}
+
+#[derive(Validatable)]
+struct Page<T> {
+    items: Vec<T>,
+    #[validate(minimum = 1)]
+    page: u32,
+}
+
+#[derive(Validatable)]
+enum Either<L, R> {
+    Left(L),
+    Right { value: R },
+}
+
+#[test]
+fn validate_generic_types() {
+    let value = Page {
+        items: vec![UnnamedOption(Some(3)), UnnamedOption(Some(2))],
+        page: 0,
+    };
+
+    let paths = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["items[1].0", "page"]);
+    assert!(Page::<i32> {
+        items: vec![1],
+        page: 1
+    }
+    .validate()
+    .is_ok());
+
+    let value = Either::<UnnamedOption, Page<i32>>::Left(UnnamedOption(Some(1)));
+    assert_eq!(value.validate().unwrap_err()[0].path, "Left._0.0");
+
+    let value = Either::<UnnamedOption, Page<i32>>::Right {
+        value: Page {
+            items: vec![],
+            page: 0,
+        },
+    };
+    assert_eq!(value.validate().unwrap_err()[0].path, "Right.value.page");
+}
+
+#[allow(dead_code)]
+#[derive(Validatable)]
+struct ExternalTypes {
+    #[validate(skip)]
+    address: std::net::IpAddr,
+    #[validate(skip, minimum = 10)]
+    timeout: u32,
+    #[validate(minimum = 1)]
+    retries: u32,
+}
+
+#[test]
+fn skip_external_types() {
+    let value = ExternalTypes {
+        address: std::net::IpAddr::from([127, 0, 0, 1]),
+        timeout: 1,
+        retries: 0,
+    };
+
+    let paths = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["retries"]);
+    assert!(ExternalTypesValidator::constraints()
+        .iter()
+        .all(|constraint| constraint.field != "timeout"));
+}
+
+#[cfg(feature = "regex")]
+#[derive(ToSchema, Validatable)]
+struct Theme {
+    #[schema(pattern = "^#[0-9a-f]{6}$")]
+    color: String,
+}
+
+#[cfg(feature = "regex")]
+#[derive(ToSchema, Validatable)]
+struct Settings {
+    settings: HashMap<String, Theme>,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn nested_map_values() {
+    let value = Settings {
+        settings: HashMap::from([
+            (
+                "theme".to_owned(),
+                Theme {
+                    color: "red".to_owned(),
+                },
+            ),
+            (
+                "fallback".to_owned(),
+                Theme {
+                    color: "#000000".to_owned(),
+                },
+            ),
+        ]),
+    };
+
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "settings[\"theme\"].color");
+}
+
+#[derive(ToSchema, Validatable)]
+struct CustomMessages {
+    #[validate(message = "Age must be between 0 and 120.")]
+    #[schema(minimum = 0, maximum = 120)]
+    age: i32,
+    #[schema(minimum = 1)]
+    count: i32,
+}
+
+#[test]
+fn custom_messages() {
+    let value = CustomMessages { age: 121, count: 0 };
+
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors[0].category, ValidationErrorCategory::Maximum);
+    assert_eq!(
+        errors[0].custom_message.as_deref(),
+        Some("Age must be between 0 and 120.")
+    );
+    assert_eq!(errors[0].to_string(), "Age must be between 0 and 120.");
+    assert_eq!(errors[1].custom_message, None);
+    assert_eq!(
+        errors[1].to_string(),
+        "count: Must be greater than or equal to 1 but is 0"
+    );
+    assert_eq!(
+        value.validate_first().unwrap_err().to_string(),
+        "Age must be between 0 and 120."
+    );
+}
+
+#[derive(ToSchema, Validatable, PartialEq)]
+#[validate(changed)]
+struct ChangedProfile {
+    #[schema(min_length = 3)]
+    name: String,
+    #[schema(minimum = 0)]
+    age: i32,
+    contact: ChangedContact,
+}
+
+#[derive(ToSchema, Validatable, PartialEq)]
+#[validate(changed)]
+enum ChangedContact {
+    Email(#[schema(min_length = 3)] String),
+    Phone(#[schema(min_length = 5)] String),
+}
+
+#[test]
+fn validate_changed() {
+    let previous = ChangedProfile {
+        name: "ab".to_owned(),
+        age: 1,
+        contact: ChangedContact::Email("a".to_owned()),
+    };
+
+    let value = ChangedProfile {
+        name: "ab".to_owned(),
+        age: -1,
+        contact: ChangedContact::Email("a".to_owned()),
+    };
+    let errors = value.validate_changed(&previous).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "age");
+    assert_eq!(value.validate().unwrap_err().len(), 3);
+
+    let value = ChangedProfile {
+        name: "ab".to_owned(),
+        age: 1,
+        contact: ChangedContact::Phone("123".to_owned()),
+    };
+    let errors = value.validate_changed(&previous).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "contact.Phone._0");
+
+    assert!(previous.validate_changed(&previous).is_ok());
+}
+
+#[derive(Deserialize, ToSchema, Validatable)]
+#[serde(tag = "t", content = "c")]
+enum AdjacentlyTagged {
+    Named {
+        #[schema(min_length = 3)]
+        name: String,
+    },
+    Newtype(#[schema(minimum = 0)] i32),
+}
+
+#[test]
+fn adjacently_tagged_paths() {
+    let value = AdjacentlyTagged::Named {
+        name: "a".to_owned(),
+    };
+    assert_eq!(value.validate().unwrap_err()[0].path, "c.name");
+
+    let value = AdjacentlyTagged::Newtype(-1);
+    assert_eq!(value.validate().unwrap_err()[0].path, "c");
+
+    assert_eq!(
+        AdjacentlyTaggedValidator::constraints()
+            .iter()
+            .map(|constraint| constraint.field)
+            .collect::<Vec<_>>(),
+        vec!["c.name", "c"]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct AsciiUser {
+    #[validate(ascii)]
+    username: String,
+    #[validate(ascii)]
+    aliases: Vec<String>,
+}
+
+#[test]
+fn validate_ascii() {
+    let value = AsciiUser {
+        username: "john_doe-1".to_owned(),
+        aliases: vec!["jd".to_owned()],
+    };
+    assert!(value.validate().is_ok());
+
+    let value = AsciiUser {
+        username: "jöhn".to_owned(),
+        aliases: vec!["jd".to_owned(), "jd™".to_owned()],
+    };
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors[0].category, ValidationErrorCategory::Ascii);
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "username: Must only contain ASCII characters but contains ö",
+            "aliases[1]: Must only contain ASCII characters but contains ™",
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct LengthUnits {
+    #[schema(max_length = 4)]
+    chars: String,
+    #[schema(max_length = 4, min_length = 2)]
+    #[validate(length = "bytes")]
+    bytes: String,
+    #[schema(max_length = 4)]
+    #[validate(length = "bytes")]
+    items: Vec<String>,
+}
+
+#[test]
+fn length_units() {
+    let value = LengthUnits {
+        chars: "äöüß".to_owned(),
+        bytes: "äö".to_owned(),
+        items: vec!["ab".to_owned()],
+    };
+    assert!(value.validate().is_ok());
+
+    let value = LengthUnits {
+        chars: "äöüßa".to_owned(),
+        bytes: "äöü".to_owned(),
+        items: vec!["ä".to_owned(), "aaaa".to_owned(), "ääa".to_owned()],
+    };
+    let messages = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "chars: Must have at most 4 characters but has 5",
+            "bytes: Must have at most 4 bytes but has 6",
+            "items[2]: Must have at most 4 bytes but has 5",
+        ]
+    );
+
+    let value = LengthUnits {
+        chars: "".to_owned(),
+        bytes: "a".to_owned(),
+        items: vec![],
+    };
+    let errors = value.validate().unwrap_err();
+    assert_eq!(errors[0].category, ValidationErrorCategory::MinByteLength);
+    assert_eq!(
+        (errors[0].actual.as_str(), errors[0].expected.as_str()),
+        ("1", "2")
+    );
+}
+
+#[derive(Validatable)]
+struct BorrowedByteLengths<'a> {
+    #[validate(max_length = 4, length = "bytes")]
+    name: &'a str,
+    #[validate(bytes(max_len = 2))]
+    data: &'a [u8],
+}
+
+#[test]
+fn borrowed_byte_lengths() {
+    let value = BorrowedByteLengths {
+        name: "äö",
+        data: &[1, 2],
+    };
+    assert!(value.validate().is_ok());
+
+    let value = BorrowedByteLengths {
+        name: "äöü",
+        data: &[1, 2, 3],
+    };
+    let categories = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| (error.path, error.category))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        vec![
+            ("name".to_owned(), ValidationErrorCategory::MaxByteLength),
+            ("data".to_owned(), ValidationErrorCategory::MaxByteLength),
+        ]
+    );
+}
+
+#[derive(ToSchema, Validatable)]
+struct Counters {
+    #[schema(value_type = u32)]
+    #[validate(maximum = 10)]
+    wrapping: std::num::Wrapping<u32>,
+    #[schema(value_type = i64)]
+    #[validate(minimum = 0)]
+    saturating: Option<std::num::Saturating<i64>>,
+}
+
+#[test]
+fn arithmetic_wrappers() {
+    use std::num::{Saturating, Wrapping};
+
+    let value = Counters {
+        wrapping: Wrapping(10),
+        saturating: Some(Saturating(0)),
+    };
+    assert!(value.validate().is_ok());
+
+    let value = Counters {
+        wrapping: Wrapping(11),
+        saturating: Some(Saturating(-1)),
+    };
+    let messages = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        messages,
+        vec![
+            "wrapping: Must be less than or equal to 10 but is 11",
+            "saturating: Must be greater than or equal to 0 but is -1",
+        ]
+    );
+    assert!(Wrapping(1u8).validate().is_ok());
+}
+
+#[derive(ToSchema, Validatable)]
+struct OptionalScores {
+    #[schema(min_items = 1, max_items = 3, minimum = 0)]
+    scores: Option<Vec<i32>>,
+}
+
+#[test]
+fn optional_vec_item_count_and_items() {
+    assert!(OptionalScores { scores: None }.validate().is_ok());
+    assert!(OptionalScores {
+        scores: Some(vec![0, 1, 2])
+    }
+    .validate()
+    .is_ok());
+
+    let categories = |scores| {
+        OptionalScores { scores }
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|error| (error.category, error.path))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        categories(Some(vec![])),
+        vec![(ValidationErrorCategory::MinItems, "scores".to_owned())]
+    );
+    assert_eq!(
+        categories(Some(vec![1, -1, 2, -2])),
+        vec![
+            (ValidationErrorCategory::MaxItems, "scores".to_owned()),
+            (ValidationErrorCategory::Minimum, "scores[1]".to_owned()),
+            (ValidationErrorCategory::Minimum, "scores[3]".to_owned()),
+        ]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[derive(ToSchema, Validatable)]
+struct SignupForm {
+    #[schema(min_length = 3, pattern = "^[a-z]+$")]
+    name: String,
+    #[schema(minimum = 18)]
+    age: i32,
+    #[schema(max_length = 10)]
+    city: String,
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn validate_grouped() {
+    let value = SignupForm {
+        name: "A".to_owned(),
+        age: 17,
+        city: "Berlin".to_owned(),
+    };
+
+    let groups = value.validate_grouped().unwrap_err();
+    assert_eq!(
+        groups.keys().map(String::as_str).collect::<Vec<_>>(),
+        vec!["age", "name"]
+    );
+    assert_eq!(
+        groups["name"]
+            .iter()
+            .map(|error| error.category.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            ValidationErrorCategory::MinLength,
+            ValidationErrorCategory::Pattern
+        ]
+    );
+    assert_eq!(groups["age"].len(), 1);
+}
+
+#[derive(ToSchema, Validatable)]
+struct RangeBounds {
+    #[validate(range = "1..10")]
+    exclusive: i32,
+    #[validate(range = "1..=10")]
+    inclusive: i32,
+    #[validate(range = 0.5..)]
+    open: Vec<f64>,
+}
+
+#[test]
+fn range_bounds() {
+    let value = RangeBounds {
+        exclusive: 9,
+        inclusive: 10,
+        open: vec![0.5, 100.0],
+    };
+    assert!(value.validate().is_ok());
+
+    let value = RangeBounds {
+        exclusive: 10,
+        inclusive: 11,
+        open: vec![0.4],
+    };
+    let categories = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| (error.path, error.category))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        vec![
+            (
+                "exclusive".to_owned(),
+                ValidationErrorCategory::ExclusiveMaximum
+            ),
+            ("inclusive".to_owned(), ValidationErrorCategory::Maximum),
+            ("open[0]".to_owned(), ValidationErrorCategory::Minimum),
+        ]
+    );
+
+    let value = RangeBounds {
+        exclusive: 0,
+        inclusive: 0,
+        open: vec![],
+    };
+    assert_eq!(value.validate().unwrap_err().len(), 2);
+}
+
+#[derive(ToSchema, Validatable)]
+struct Dates {
+    #[schema(format = Date)]
+    date: String,
+    #[schema(format = DateTime)]
+    date_time: String,
+    #[schema(format = "date-time")]
+    timestamps: Vec<String>,
+}
+
+#[test]
+fn date_formats() {
+    let value = Dates {
+        date: "2024-02-29".to_owned(),
+        date_time: "2023-12-31T23:59:60Z".to_owned(),
+        timestamps: vec![
+            "2023-01-01t00:00:00.123456+01:30".to_owned(),
+            "2023-01-01T12:30:00-05:00".to_owned(),
+            "2023-01-01T12:30:00z".to_owned(),
+        ],
+    };
+    assert!(value.validate().is_ok());
+
+    for date in [
+        "2023-02-29",
+        "2023-13-01",
+        "2023-1-01",
+        "23-01-01",
+        "2023-01-01T",
+    ] {
+        let value = Dates {
+            date: date.to_owned(),
+            date_time: "2023-01-01T00:00:00Z".to_owned(),
+            timestamps: vec![],
+        };
+        let errors = value.validate().unwrap_err();
+        assert_eq!(
+            errors[0].category,
+            ValidationErrorCategory::Format { format: "date" },
+            "{}",
+            date
+        );
+    }
+
+    for date_time in [
+        "2023-01-01",
+        "2023-01-01T00:00:00",
+        "2023-01-01 00:00:00Z",
+        "2023-01-01T24:00:00Z",
+        "2023-01-01T00:60:00Z",
+        "2023-01-01T00:00:00.Z",
+        "2023-01-01T00:00:00+0100",
+        "2023-02-30T00:00:00Z",
+    ] {
+        let value = Dates {
+            date: "2023-01-01".to_owned(),
+            date_time: date_time.to_owned(),
+            timestamps: vec![],
+        };
+        let errors = value.validate().unwrap_err();
+        assert_eq!(
+            errors[0].to_string(),
+            format!("date_time: Must be a valid date-time but is {}", date_time)
+        );
+    }
+}
+
+#[derive(Validatable)]
+struct BorrowedDates<'a> {
+    #[validate(format = Date)]
+    date: &'a str,
+    #[validate(format = DateTime)]
+    date_time: Cow<'a, str>,
+}
+
+#[test]
+fn borrowed_date_formats() {
+    let value = BorrowedDates {
+        date: "2024-02-29",
+        date_time: Cow::Borrowed("2023-01-01T00:00:00Z"),
+    };
+    assert!(value.validate().is_ok());
+
+    let value = BorrowedDates {
+        date: "2023-02-29",
+        date_time: Cow::Owned("2023-01-01T00:00:00".to_owned()),
+    };
+    let paths = value
+        .validate()
+        .unwrap_err()
+        .into_iter()
+        .map(|error| error.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec!["date", "date_time"]);
+}
+
+#[derive(ToSchema, Validatable)]
+struct Hosts {
+    #[validate(format = Ipv4)]
+    v4: String,
+    #[validate(format = Ipv6)]
+    v6: String,
+}
+
+#[test]
+fn ip_formats() {
+    let value = Hosts {
+        v4: "192.168.0.1".to_owned(),
+        v6: "2001:db8::1".to_owned(),
+    };
+    assert!(value.validate().is_ok());
+
+    let value = Hosts {
+        v4: "::1".to_owned(),
+        v6: "192.168.0.1".to_owned(),
+    };
+    let errors = value.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>(),
+        vec![
+            "v4: Must be a valid ipv4 but is ::1",
+            "v6: Must be a valid ipv6 but is 192.168.0.1",
+        ]
+    );
+
+    for (v4, v6) in [
+        ("256.0.0.1", "2001:db8:::1"),
+        ("1.2.3", "12345::"),
+        ("", ""),
+    ] {
+        let value = Hosts {
+            v4: v4.to_owned(),
+            v6: v6.to_owned(),
+        };
+        let categories = value
+            .validate()
+            .unwrap_err()
+            .into_iter()
+            .map(|error| error.category)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            categories,
+            vec![
+                ValidationErrorCategory::Format { format: "ipv4" },
+                ValidationErrorCategory::Format { format: "ipv6" },
+            ]
+        );
+    }
+}
+
+#[derive(Validatable)]
+struct BorrowedHosts<'a> {
+    #[validate(format = Ipv4)]
+    v4: &'a str,
+    #[validate(format = Ipv6)]
+    v6: Option<Cow<'a, str>>,
+}
+
+#[test]
+fn borrowed_ip_formats() {
+    let value = BorrowedHosts {
+        v4: "192.168.0.1",
+        v6: Some(Cow::Borrowed("2001:db8::1")),
+    };
+    assert!(value.validate().is_ok());
+
+    let value = BorrowedHosts {
+        v4: "::1",
+        v6: Some(Cow::Borrowed("192.168.0.1")),
+    };
+    assert_eq!(value.validate().unwrap_err().len(), 2);
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "schemars")]
+
+use schemars::{schema_for, JsonSchema};
+use serde::Serialize;
+use serde_json::json;
+use utoipa_validate::{
+    SchemarsValidator, Severity, ValidationError, ValidationErrorCategory, ValidationPath,
+    Validator,
+};
+
+#[derive(JsonSchema, Serialize)]
+struct Inner {
+    #[schemars(length(max = 3))]
+    name: String,
+}
+
+#[derive(JsonSchema, Serialize)]
+struct Outer {
+    #[schemars(range(max = 5))]
+    value: i32,
+    inner: Vec<Inner>,
+}
+
+#[test]
+fn valid_schemars() {
+    let validator = SchemarsValidator::new(schema_for!(Outer));
+    let value = Outer {
+        value: 5,
+        inner: vec![Inner {
+            name: "abc".to_owned(),
+        }],
+    };
+
+    let mut errors = Vec::new();
+    validator.validate(&ValidationPath::Root, &value, &mut errors);
+
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn invalid_schemars() {
+    let validator = SchemarsValidator::new(schema_for!(Outer));
+    let value = Outer {
+        value: 6,
+        inner: vec![Inner {
+            name: "abcd".to_owned(),
+        }],
+    };
+
+    let mut errors = Vec::new();
+    validator.validate(&ValidationPath::Root, &value, &mut errors);
+
+    assert_eq!(
+        errors,
+        vec![
+            ValidationError {
+                category: ValidationErrorCategory::MaxLength,
+                path: "inner[0].name".to_owned(),
+                actual: "4".to_owned(),
+                expected: "3".to_owned(),
+                index: Some(0),
+                custom_message: None,
+                severity: Severity::Error,
+            },
+            ValidationError {
+                category: ValidationErrorCategory::Maximum,
+                path: "value".to_owned(),
+                actual: "6".to_owned(),
+                expected: "5".to_owned(),
+                index: None,
+                custom_message: None,
+                severity: Severity::Error,
+            },
+        ]
+    );
+}
+
+#[test]
+fn schemars_multiple_of() {
+    let schema = serde_json::from_value(json!({ "multipleOf": 0.1 })).unwrap();
+    let validator = SchemarsValidator::<f64>::new(schema);
+
+    let mut errors = Vec::new();
+    validator.validate(&ValidationPath::Root, &0.3, &mut errors);
+    assert!(errors.is_empty());
+
+    validator.validate(&ValidationPath::Root, &0.35, &mut errors);
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].category, ValidationErrorCategory::MultipleOf);
+}
+
+#[derive(JsonSchema, Serialize)]
+struct Patterns {
+    #[schemars(regex(pattern = "^[0-9a-f]+$"))]
+    hex: String,
+    #[schemars(regex(pattern = "^[0-9a-f+$"))]
+    invalid: String,
+}
+
+#[test]
+fn schemars_patterns() {
+    let validator = SchemarsValidator::new(schema_for!(Patterns));
+    let value = Patterns {
+        hex: "xyz".to_owned(),
+        invalid: "abc".to_owned(),
+    };
+
+    let mut errors = Vec::new();
+    validator.validate(&ValidationPath::Root, &value, &mut errors);
+
+    let tags = errors
+        .iter()
+        .map(|error| (error.path.as_str(), error.category.tag()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tags,
+        vec![("hex", "pattern"), ("invalid", "invalid_pattern")]
+    );
+}
+
+struct Unserializable;
+
+impl Serialize for Unserializable {
+    fn serialize<S: serde::Serializer>(&self, _serializer: S) -> Result<S::Ok, S::Error> {
+        Err(serde::ser::Error::custom("unsupported"))
+    }
+}
+
+#[test]
+fn schemars_unserializable() {
+    let validator = SchemarsValidator::<Unserializable>::new(schema_for!(String));
+
+    let mut errors = Vec::new();
+    validator.validate(&ValidationPath::Root, &Unserializable, &mut errors);
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].category.tag(), "serialization");
+    assert_eq!(
+        errors[0].to_string(),
+        ": Could not be serialized: unsupported"
+    );
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "serde")]
+
+use serde_json::json;
+use utoipa_validate::{Severity, ValidationError, ValidationErrorCategory};
+
+#[test]
+fn serialize_errors() {
+    let errors = vec![
+        ValidationError {
+            category: ValidationErrorCategory::MaxLength,
+            path: "items[1].name".to_owned(),
+            index: Some(1),
+            actual: "6".to_owned(),
+            expected: "5".to_owned(),
+            custom_message: None,
+            severity: Severity::Error,
+        },
+        ValidationError {
+            category: ValidationErrorCategory::Other {
+                tag: "custom",
+                display: |error, f| write!(f, "{}: Custom", error.path),
+                detail: None,
+            },
+            path: "value".to_owned(),
+            index: None,
+            actual: "a".to_owned(),
+            expected: "b".to_owned(),
+            custom_message: Some("Value must be b".to_owned()),
+            severity: Severity::Warning,
+        },
+    ];
+
+    assert_eq!(
+        serde_json::to_value(&errors).unwrap(),
+        json!([
+            {
+                "category": "max_length",
+                "path": "items[1].name",
+                "index": 1,
+                "actual": "6",
+                "expected": "5"
+            },
+            {
+                "category": "custom",
+                "path": "value",
+                "index": null,
+                "actual": "a",
+                "expected": "b",
+                "message": "Value must be b",
+                "severity": "warning"
+            }
+        ])
+    );
+}
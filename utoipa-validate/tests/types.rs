@@ -0,0 +1,221 @@
+use std::ops::ControlFlow;
+use utoipa_validate::{
+    ChainValidator, MaximumValidator, MinimumValidator, MultipleOfValidator, OrderedValidator,
+    PositionalValidator, RangeValidator, Severity, Validatable, ValidationError,
+    ValidationErrorCategory, ValidationPath, Validator,
+};
+
+#[test]
+fn valid_unit() {
+    let result = ().validate();
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn valid_range() {
+    assert!((1u32..2).validate().is_ok());
+    assert!((1u32..1).validate().is_ok());
+    assert!((1u32..=1).validate().is_ok());
+}
+
+#[allow(clippy::reversed_empty_ranges)]
+#[test]
+fn invalid_range() {
+    let result = (3u32..2).validate();
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::RangeOrder,
+            path: "".to_owned(),
+            actual: "3".to_owned(),
+            expected: "2".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+
+    let result = (3u32..=5).validate_with(&RangeValidator::new(MaximumValidator::new(4)));
+
+    assert!(result.is_err());
+    let error = result.unwrap_err();
+    assert_eq!(error.len(), 1);
+    assert_eq!(
+        error[0],
+        ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "end".to_owned(),
+            actual: "5".to_owned(),
+            expected: "4".to_owned(),
+            index: None,
+            custom_message: None,
+            severity: Severity::Error,
+        }
+    );
+}
+
+#[test]
+fn positional_validator() {
+    let validator = PositionalValidator::new(vec![
+        Box::new(MaximumValidator::new(10)) as Box<dyn Validator<i32>>,
+        Box::new(MinimumValidator::new(5)),
+    ]);
+
+    assert!(vec![10, 5].validate_with(&validator).is_ok());
+
+    let errors = vec![11, 4].validate_with(&validator).unwrap_err();
+    let paths = errors
+        .iter()
+        .map(|error| (error.category.tag(), error.path.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(paths, vec![("maximum", "[0]"), ("minimum", "[1]")]);
+
+    let errors = vec![1].validate_with(&validator).unwrap_err();
+    assert_eq!(
+        errors,
+        vec![ValidationError {
+            category: ValidationErrorCategory::MinItems,
+            path: "".to_owned(),
+            index: None,
+            actual: "1".to_owned(),
+            expected: "2".to_owned(),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+
+    let errors = vec![1, 5, 3].validate_with(&validator).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].category, ValidationErrorCategory::MaxItems);
+}
+
+#[test]
+fn chain_validator() {
+    let validator = ChainValidator::new(vec![
+        Box::new(MinimumValidator::new(0)),
+        Box::new(MultipleOfValidator::new(5)),
+    ]);
+
+    assert!(10.validate_with(&validator).is_ok());
+
+    let errors = (-3).validate_with(&validator).unwrap_err();
+    let categories = errors
+        .iter()
+        .map(|error| error.category.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        vec![
+            ValidationErrorCategory::Minimum,
+            ValidationErrorCategory::MultipleOf
+        ]
+    );
+
+    match validator.validate_first(&ValidationPath::Root, &-3) {
+        ControlFlow::Break(error) => assert_eq!(error.category, ValidationErrorCategory::Minimum),
+        ControlFlow::Continue(()) => panic!("Expected an error"),
+    }
+}
+
+#[test]
+fn ordered_validator() {
+    let validator = OrderedValidator::new(None, Some("m".to_owned()), |a: &String, b: &String| {
+        a.to_lowercase().cmp(&b.to_lowercase())
+    });
+
+    assert!("M".to_owned().validate_with(&validator).is_ok());
+    assert!("abc".to_owned().validate_with(&validator).is_ok());
+    assert_eq!(
+        "N".to_owned().validate_with(&validator).unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Maximum,
+            path: "".to_owned(),
+            index: None,
+            actual: "N".to_owned(),
+            expected: "m".to_owned(),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn not_validator() {
+    use utoipa_validate::regex::Regex;
+    use utoipa_validate::{NotValidator, PatternValidator};
+
+    let validator = NotValidator::new(
+        PatternValidator::new(Regex::new("^[0-9]+$").unwrap()),
+        "a number",
+    );
+
+    assert!("abc".to_owned().validate_with(&validator).is_ok());
+    assert_eq!(
+        "123".to_owned().validate_with(&validator).unwrap_err(),
+        vec![ValidationError {
+            category: ValidationErrorCategory::Not,
+            path: "".to_owned(),
+            index: None,
+            actual: "123".to_owned(),
+            expected: "a number".to_owned(),
+            custom_message: None,
+            severity: Severity::Error,
+        }]
+    );
+    assert_eq!(
+        "123".to_owned().validate_with(&validator).unwrap_err()[0].to_string(),
+        ": Must not be a number but is 123"
+    );
+}
+
+#[test]
+fn float_multiple_of() {
+    for value in [0.3, 0.7, -0.3, 0.0, 12.1] {
+        assert!(value.validate_with(&MultipleOfValidator::new(0.1)).is_ok());
+    }
+    assert!(0.3f32
+        .validate_with(&MultipleOfValidator::new(0.1f32))
+        .is_ok());
+
+    for value in [0.35, 0.01, 1.05] {
+        let errors = value
+            .validate_with(&MultipleOfValidator::new(0.1))
+            .unwrap_err();
+        assert_eq!(errors[0].category, ValidationErrorCategory::MultipleOf);
+    }
+
+    assert!(7.validate_with(&MultipleOfValidator::new(7)).is_ok());
+    assert!(8.validate_with(&MultipleOfValidator::new(7)).is_err());
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn pattern_max_input_len() {
+    use utoipa_validate::regex::Regex;
+    use utoipa_validate::PatternValidator;
+
+    let validator =
+        PatternValidator::new(Regex::new("^(a|b)*c$").unwrap()).with_max_input_len(1024);
+
+    assert!("abc".to_owned().validate_with(&validator).is_ok());
+    assert_eq!(
+        "abd".to_owned().validate_with(&validator).unwrap_err()[0].category,
+        ValidationErrorCategory::Pattern
+    );
+
+    // The value would match, so the error proves that the regex is not run.
+    let value = format!("{}c", "ab".repeat(1024 * 1024));
+    let errors = value.validate_with(&validator).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].category.tag(), "pattern_input_too_long");
+    assert_eq!(
+        errors[0].to_string(),
+        ": Must have at most 1024 bytes to be matched but has 2097153"
+    );
+}
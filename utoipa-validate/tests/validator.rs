@@ -0,0 +1,122 @@
+#![cfg(feature = "validator")]
+
+use utoipa::ToSchema;
+use utoipa_validate::{Validatable, ValidationErrors};
+use validator::ValidationErrorsKind;
+
+#[derive(ToSchema, Validatable)]
+struct Item {
+    #[schema(minimum = 1)]
+    quantity: i32,
+}
+
+#[derive(ToSchema, Validatable)]
+struct Customer {
+    #[schema(min_length = 3)]
+    name: String,
+}
+
+#[derive(ToSchema, Validatable)]
+struct Order {
+    #[schema(max_length = 5)]
+    id: String,
+    customer: Customer,
+    items: Vec<Item>,
+    #[schema(max_items = 1, minimum = 0)]
+    codes: Vec<i32>,
+}
+
+#[test]
+fn convert_nested_errors() {
+    let order = Order {
+        id: "123456".to_owned(),
+        customer: Customer {
+            name: "ab".to_owned(),
+        },
+        items: vec![Item { quantity: 1 }, Item { quantity: 0 }],
+        codes: vec![1, -1],
+    };
+
+    let errors: validator::ValidationErrors =
+        ValidationErrors(order.validate().unwrap_err()).into();
+
+    let field_errors = errors.field_errors();
+    let id = field_errors["id"];
+    assert_eq!(id.len(), 1);
+    assert_eq!(id[0].code, "length");
+    assert_eq!(
+        id[0].message.as_deref(),
+        Some("id: Must have at most 5 characters but has 6")
+    );
+    assert_eq!(id[0].params["max"], "5");
+    assert_eq!(id[0].params["value"], "6");
+
+    // Nested errors of fields with own errors are added to the errors of the field.
+    let codes = field_errors["codes"]
+        .iter()
+        .map(|error| error.code.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(codes, vec!["length", "range"]);
+
+    match &errors.errors()["customer"] {
+        ValidationErrorsKind::Struct(customer) => {
+            assert_eq!(customer.field_errors()["name"][0].code, "length");
+            assert_eq!(customer.field_errors()["name"][0].params["min"], "3");
+        }
+        kind => panic!("Expected struct errors but got {:?}", kind),
+    }
+
+    match &errors.errors()["items"] {
+        ValidationErrorsKind::List(items) => {
+            assert_eq!(items.keys().copied().collect::<Vec<_>>(), vec![1]);
+            assert_eq!(items[&1].field_errors()["quantity"][0].code, "range");
+        }
+        kind => panic!("Expected list errors but got {:?}", kind),
+    }
+}
+
+#[derive(ToSchema, Validatable)]
+struct Inventory {
+    #[validate(key(max_length = 1))]
+    stock: std::collections::BTreeMap<String, i32>,
+    #[validate(value(maximum = 10))]
+    prices: std::collections::BTreeMap<String, i32>,
+}
+
+#[test]
+fn convert_numeric_key_errors() {
+    let inventory = Inventory {
+        stock: std::collections::BTreeMap::from([("10".to_owned(), 1)]),
+        prices: std::collections::BTreeMap::new(),
+    };
+
+    let errors: validator::ValidationErrors =
+        ValidationErrors(inventory.validate().unwrap_err()).into();
+
+    // Numeric keys are handled like fields instead of list items.
+    match &errors.errors()["stock"] {
+        ValidationErrorsKind::Struct(stock) => {
+            assert_eq!(stock.field_errors()["10"][0].code, "length");
+        }
+        kind => panic!("Expected struct errors but got {:?}", kind),
+    }
+}
+
+#[test]
+fn convert_map_keys_with_separators() {
+    let inventory = Inventory {
+        stock: std::collections::BTreeMap::new(),
+        prices: std::collections::BTreeMap::from([("a.b[0]".to_owned(), 11)]),
+    };
+
+    let errors: validator::ValidationErrors =
+        ValidationErrors(inventory.validate().unwrap_err()).into();
+
+    // Keys are single fields even if they contain path separators.
+    match &errors.errors()["prices"] {
+        ValidationErrorsKind::Struct(prices) => {
+            assert_eq!(prices.field_errors()["a.b[0]"][0].code, "range");
+        }
+        kind => panic!("Expected struct errors but got {:?}", kind),
+    }
+}
@@ -0,0 +1,41 @@
+//! The validation only relies on primitives that are available on single-threaded targets like
+//! `wasm32-unknown-unknown`. Check via `cargo test --target wasm32-unknown-unknown --no-run`.
+#![cfg(feature = "regex")]
+
+use std::sync::OnceLock;
+use utoipa::ToSchema;
+use utoipa_validate::Validatable;
+
+#[derive(ToSchema, Validatable)]
+struct Identifier {
+    #[schema(pattern = "^[a-z]+$")]
+    pub name: String,
+}
+
+static VALIDATOR: OnceLock<IdentifierValidator> = OnceLock::new();
+
+#[test]
+fn shared_validator() {
+    let validator = VALIDATOR.get_or_init(IdentifierValidator::default);
+
+    let value = Identifier {
+        name: "abc".to_owned(),
+    };
+    assert!(value.validate_with(validator).is_ok());
+
+    let value = Identifier {
+        name: "ABC".to_owned(),
+    };
+    assert_eq!(value.validate_with(validator).unwrap_err().len(), 1);
+}
+
+#[test]
+fn repeated_pattern_validation() {
+    for name in ["a", "b", "1"] {
+        let value = Identifier {
+            name: name.to_owned(),
+        };
+
+        assert_eq!(value.is_valid(), name != "1");
+    }
+}